@@ -0,0 +1,72 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Hash of the code of each day, for the cache of answers
+//!
+//! A day is compiled from its own crate and the `aoc` library, so its
+//! hash covers the sources and manifests of both, plus the profile. The
+//! hasher is std's, which is deterministic for a given toolchain; a new
+//! toolchain changes the hashes, as it can change the compiled code.
+
+use std::collections::hash_map::DefaultHasher;
+use std::env;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Every file under `path`, sorted
+fn files(path: &Path, out: &mut Vec<PathBuf>) -> io::Result<()> {
+    if path.is_dir() {
+        let mut entries = fs::read_dir(path)?
+            .map(|e| e.map(|e| e.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        entries.sort();
+        for entry in entries {
+            files(&entry, out)?;
+        }
+    } else {
+        out.push(path.to_path_buf());
+    }
+    Ok(())
+}
+
+fn hash_crate(dir: &Path, hasher: &mut DefaultHasher) -> io::Result<()> {
+    for sub in ["Cargo.toml", "src"] {
+        let path = dir.join(sub);
+        println!("cargo:rerun-if-changed={}", path.display());
+        let mut paths = vec![];
+        files(&path, &mut paths)?;
+        for path in paths {
+            path.strip_prefix(dir).unwrap().hash(hasher);
+            fs::read(&path)?.hash(hasher);
+        }
+    }
+    Ok(())
+}
+
+fn main() -> io::Result<()> {
+    let workspace = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap())
+        .parent()
+        .unwrap()
+        .to_path_buf();
+    let mut common = DefaultHasher::new();
+    env::var("PROFILE").unwrap().hash(&mut common);
+    hash_crate(&workspace.join("aoc"), &mut common)?;
+    let mut hashes = vec![];
+    for day in 1..=25 {
+        let mut hasher = common.clone();
+        hash_crate(&workspace.join(format!("day{:02}", day)), &mut hasher)?;
+        hashes.push(format!("{:#018x}", hasher.finish()));
+    }
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("code_hashes.rs");
+    fs::write(
+        out,
+        format!(
+            "/// Hash of the code of each day, from day 1\n\
+             const CODE_HASHES: [u64; 25] = [{}];\n",
+            hashes.join(", ")
+        ),
+    )
+}
@@ -0,0 +1,108 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Database of the known answers for our inputs
+//!
+//! One answer per line, after the name of the solution:
+//!
+//! ```text
+//! # solution  answer
+//! day06a      4711
+//! ```
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+
+use aoc::{Location, PuzzleError, Result};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u32, char), String>);
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Answers> {
+        let mut answers = BTreeMap::default();
+        for (i, text) in input.lines().enumerate() {
+            let location = Location {
+                line: i + 1,
+                column: 1,
+            };
+            let text = text.split_once('#').map_or(text, |s| s.0);
+            let mut fields = text.split_whitespace();
+            let Some(name) = fields.next() else {
+                continue;
+            };
+            let (Some(key), Some(answer), None) = (parse_name(name), fields.next(), fields.next())
+            else {
+                return Err(PuzzleError::invalid_input_at(
+                    location,
+                    format!("expected dayNNp and answer in {:?}", text),
+                )
+                .into());
+            };
+            answers.insert(key, answer.to_string());
+        }
+        Ok(Answers(answers))
+    }
+
+    /// Load the database; a missing file is an empty one
+    pub fn load(path: &Path) -> Result<Answers> {
+        match fs::read_to_string(path) {
+            Ok(input) => Answers::parse(&input),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn get(&self, day: u32, part: char) -> Option<&str> {
+        self.0.get(&(day, part)).map(|s| s.as_str())
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&(u32, char), &String)> {
+        self.0.iter()
+    }
+
+    /// Add an answer to the end of the database file
+    pub fn append(path: &Path, day: u32, part: char, answer: &str) -> Result<()> {
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        writeln!(file, "day{:02}{}  {}", day, part, answer)?;
+        Ok(())
+    }
+}
+
+/// Parse `day06a` into `(6, 'a')`
+fn parse_name(name: &str) -> Option<(u32, char)> {
+    let rest = name.strip_prefix("day")?;
+    let part = rest.chars().last()?;
+    let day = rest[..rest.len() - part.len_utf8()].parse().ok()?;
+    part.is_ascii_lowercase().then_some((day, part))
+}
+
+#[test]
+fn test_parse() -> Result<()> {
+    let answers = Answers::parse("# solution answer\nday06a 41\n\nday17a 4,6,3  # example\n")?;
+    assert_eq!(answers.get(6, 'a'), Some("41"));
+    assert_eq!(answers.get(17, 'a'), Some("4,6,3"));
+    assert_eq!(answers.get(6, 'b'), None);
+    assert!(Answers::parse("day06 41\n").is_err());
+    assert!(Answers::parse("day06a\n").is_err());
+    assert!(Answers::parse("day06a 41 42\n").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_append() -> Result<()> {
+    let path = std::env::temp_dir().join(format!("aoc-answers-{}.txt", std::process::id()));
+    Answers::append(&path, 6, 'a', "41")?;
+    Answers::append(&path, 6, 'b', "6")?;
+    let answers = Answers::load(&path)?;
+    fs::remove_file(&path)?;
+    assert_eq!(answers.get(6, 'a'), Some("41"));
+    assert_eq!(answers.get(6, 'b'), Some("6"));
+    Ok(())
+}
@@ -0,0 +1,206 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Cache of answers, so that slow days don't run again for nothing
+//!
+//! Answers are keyed by day, part and the hash of the input, and are
+//! valid only while the hash of the day's code, computed by the build
+//! script, stays the same. The file has one answer per line:
+//!
+//! ```text
+//! # solution  code  input  µs  answer
+//! day06b 0x0123456789abcdef 0xfedcba9876543210 1234567 1719
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use aoc::{Location, PuzzleError, Result};
+
+include!(concat!(env!("OUT_DIR"), "/code_hashes.rs"));
+
+/// Hash of the code of the day, None for days we don't have
+pub fn code_hash(day: u32) -> Option<u64> {
+    CODE_HASHES.get(day.checked_sub(1)? as usize).copied()
+}
+
+pub fn input_hash(input: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    input.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cached {
+    pub code: u64,
+    pub answer: String,
+    /// Time it took to get the answer
+    pub time: Duration,
+}
+
+/// The cache, kept at `<root>/target/aoc-cache.txt`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cache {
+    path: PathBuf,
+    entries: BTreeMap<(u32, char, u64), Cached>,
+    dirty: bool,
+}
+
+pub fn path(root: &Path) -> PathBuf {
+    root.join("target").join("aoc-cache.txt")
+}
+
+fn parse_hash(text: &str) -> Option<u64> {
+    u64::from_str_radix(text.strip_prefix("0x")?, 16).ok()
+}
+
+impl Cache {
+    pub fn parse(path: &Path, input: &str) -> Result<Cache> {
+        let mut entries = BTreeMap::default();
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.splitn(5, ' ');
+            let entry = (|| {
+                let name = fields.next()?.strip_prefix("day")?;
+                let part = name.chars().last()?;
+                let day = name[..name.len() - part.len_utf8()].parse().ok()?;
+                let code = parse_hash(fields.next()?)?;
+                let input = parse_hash(fields.next()?)?;
+                let time = Duration::from_micros(fields.next()?.parse().ok()?);
+                let answer = fields.next()?.to_string();
+                Some(((day, part, input), Cached { code, answer, time }))
+            })();
+            let Some((key, cached)) = entry else {
+                return Err(PuzzleError::invalid_input_at(
+                    Location {
+                        line: i + 1,
+                        column: 1,
+                    },
+                    format!("invalid cache entry {:?}", line),
+                )
+                .into());
+            };
+            entries.insert(key, cached);
+        }
+        Ok(Cache {
+            path: path.to_path_buf(),
+            entries,
+            dirty: false,
+        })
+    }
+
+    /// Load the cache; a missing or corrupt file is an empty cache
+    pub fn load(path: &Path) -> Cache {
+        let empty = || Cache {
+            path: path.to_path_buf(),
+            ..Cache::default()
+        };
+        match fs::read_to_string(path) {
+            Ok(input) => Cache::parse(path, &input).unwrap_or_else(|_| empty()),
+            Err(_) => empty(),
+        }
+    }
+
+    /// Answer of the part for the input, if the code didn't change
+    pub fn get(&self, day: u32, part: char, input: &str) -> Option<&Cached> {
+        let cached = self.entries.get(&(day, part, input_hash(input)))?;
+        (Some(cached.code) == code_hash(day)).then_some(cached)
+    }
+
+    pub fn insert(&mut self, day: u32, part: char, input: &str, answer: &str, time: Duration) {
+        let Some(code) = code_hash(day) else {
+            return;
+        };
+        let cached = Cached {
+            code,
+            answer: answer.to_string(),
+            time,
+        };
+        self.entries.insert((day, part, input_hash(input)), cached);
+        self.dirty = true;
+    }
+
+    /// Contents of the file, without the answers of old code
+    pub fn format(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "# solution  code  input  µs  answer").unwrap();
+        for ((day, part, input), cached) in &self.entries {
+            if Some(cached.code) != code_hash(*day) {
+                continue;
+            }
+            writeln!(
+                out,
+                "day{:02}{} {:#018x} {:#018x} {} {}",
+                day,
+                part,
+                cached.code,
+                input,
+                cached.time.as_micros(),
+                cached.answer
+            )
+            .unwrap();
+        }
+        out
+    }
+
+    /// Write the file if there are new answers
+    pub fn save(&mut self) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(&self.path, self.format())?;
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+#[test]
+fn test_cache() -> Result<()> {
+    let path = std::env::temp_dir()
+        .join(format!("aoc-cache-{}", std::process::id()))
+        .join("aoc-cache.txt");
+    let mut cache = Cache::load(&path);
+    assert_eq!(cache.get(6, 'b', "#.^\n"), None);
+    cache.insert(6, 'b', "#.^\n", "two words", Duration::from_millis(5));
+    cache.insert(99, 'a', "", "no such day", Duration::ZERO);
+    cache.save()?;
+    let mut cache = Cache::load(&path);
+    let cached = cache.get(6, 'b', "#.^\n").unwrap();
+    assert_eq!(cached.answer, "two words");
+    assert_eq!(cached.time, Duration::from_millis(5));
+    assert_eq!(cache.get(6, 'b', "#.#\n"), None);
+    assert_eq!(cache.get(99, 'a', ""), None);
+    // Answers of old code are dropped when saving
+    cache
+        .entries
+        .get_mut(&(6, 'b', input_hash("#.^\n")))
+        .unwrap()
+        .code ^= 1;
+    assert_eq!(cache.get(6, 'b', "#.^\n"), None);
+    cache.insert(7, 'a', "", "1", Duration::ZERO);
+    cache.save()?;
+    let cache = Cache::load(&path);
+    assert_eq!(cache.entries.len(), 1);
+    fs::remove_dir_all(path.parent().unwrap())?;
+    Ok(())
+}
+
+#[test]
+fn test_parse() {
+    let path = Path::new("aoc-cache.txt");
+    assert!(Cache::parse(path, "# comment\n\n").is_ok());
+    assert!(Cache::parse(path, "day06b 0x1 0x2 3 4\n").is_ok());
+    assert!(Cache::parse(path, "day06b 0x1 0x2 3\n").is_err());
+    assert!(Cache::parse(path, "day06b 1 0x2 3 4\n").is_err());
+}
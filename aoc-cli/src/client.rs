@@ -0,0 +1,203 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Client of the puzzle site: `aoc fetch` and `aoc submit`
+//!
+//! Both use the session cookie of a logged-in browser, taken from
+//! `AOC_SESSION` or from `<root>/.session`. The base URL can point to
+//! the [`mock`](crate::mock) server, which is what the tests do.
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use aoc::{eyre, Result};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u32 = 2024;
+const USER_AGENT: &str = "github.com/lpenz/adventofcode2024 by lpenz@lpenz.org";
+
+/// What the site said about a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint
+    Wrong,
+    /// Another answer was given too recently
+    RateLimited {
+        wait: Option<String>,
+    },
+    /// The part was already solved, or part a wasn't yet
+    WrongLevel,
+    /// Page we couldn't make sense of, with its main text
+    Unknown(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "wrong, too high"),
+            Outcome::TooLow => write!(f, "wrong, too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited { wait: Some(wait) } => {
+                write!(f, "rate-limited, wait {}", wait)
+            }
+            Outcome::RateLimited { wait: None } => write!(f, "rate-limited"),
+            Outcome::WrongLevel => write!(f, "not at this part, already solved?"),
+            Outcome::Unknown(text) => write!(f, "unknown response: {}", text),
+        }
+    }
+}
+
+/// Text of the `<article>` of the page, without the tags
+fn article_text(html: &str) -> String {
+    let article = html
+        .split_once("<article")
+        .and_then(|(_, rest)| rest.split_once('>'))
+        .map_or(html, |(_, rest)| {
+            rest.split_once("</article>").map_or(rest, |(a, _)| a)
+        });
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+pub fn parse_outcome(html: &str) -> Outcome {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("too high") {
+        Outcome::TooHigh
+    } else if text.contains("too low") {
+        Outcome::TooLow
+    } else if text.contains("That's not the right answer") {
+        Outcome::Wrong
+    } else if text.contains("You gave an answer too recently") {
+        let wait = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .map(|(wait, _)| wait.to_string());
+        Outcome::RateLimited { wait }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+/// Session token from `AOC_SESSION` or from `<root>/.session`
+pub fn session(root: &Path) -> Result<String> {
+    if let Ok(session) = env::var("AOC_SESSION") {
+        return Ok(session.trim().to_string());
+    }
+    let path = root.join(".session");
+    fs::read_to_string(&path)
+        .map(|s| s.trim().to_string())
+        .map_err(|e| eyre!("no AOC_SESSION and reading {}: {}", path.display(), e))
+}
+
+#[derive(Debug, Clone)]
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Client {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    fn url(&self, day: u32, what: &str) -> String {
+        format!("{}/{}/day/{}/{}", self.base_url, YEAR, day, what)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    pub fn input(&self, day: u32) -> Result<String> {
+        let response = self
+            .agent
+            .get(&self.url(day, "input"))
+            .set("Cookie", &self.cookie())
+            .call()?;
+        Ok(response.into_string()?)
+    }
+
+    pub fn submit(&self, day: u32, part: char, answer: &str) -> Result<Outcome> {
+        let level = match part {
+            'a' => "1",
+            'b' => "2",
+            _ => return Err(eyre!("invalid part {}", part)),
+        };
+        let response = self
+            .agent
+            .post(&self.url(day, "answer"))
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", level), ("answer", answer)])?;
+        Ok(parse_outcome(&response.into_string()?))
+    }
+}
+
+#[test]
+fn test_parse_outcome() {
+    let page = |text: &str| format!("<html><main><article><p>{}</p></article></main>", text);
+    assert_eq!(
+        parse_outcome(&page(
+            "That's the right answer! You are <em>one gold star</em> closer."
+        )),
+        Outcome::Correct
+    );
+    assert_eq!(
+        parse_outcome(&page(
+            "That's not the right answer; your answer is too high."
+        )),
+        Outcome::TooHigh
+    );
+    assert_eq!(
+        parse_outcome(&page(
+            "That's not the right answer; your answer is too low."
+        )),
+        Outcome::TooLow
+    );
+    assert_eq!(
+        parse_outcome(&page("That's not the right answer. If you're stuck...")),
+        Outcome::Wrong
+    );
+    assert_eq!(
+        parse_outcome(&page(
+            "You gave an answer too recently. You have 34s left to wait."
+        )),
+        Outcome::RateLimited {
+            wait: Some("34s".to_string())
+        }
+    );
+    assert_eq!(
+        parse_outcome(&page(
+            "You don't seem to be solving the right level. Did you already complete it?"
+        )),
+        Outcome::WrongLevel
+    );
+    assert_eq!(
+        parse_outcome("<p>Not <b>here</b></p>"),
+        Outcome::Unknown("Not here".to_string())
+    );
+}
@@ -0,0 +1,186 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! `aoc report --cross-check`: run every strategy of the parts that have
+//! alternatives on the same input, and compare them with the main one
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use aoc::{Result, Solution};
+
+use crate::inputs;
+use crate::report::{format_duration, time};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Run {
+    pub strategy: &'static str,
+    pub outcome: Result<(String, Duration), String>,
+}
+
+/// All the strategies of a part, the main one first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub day: u32,
+    pub part: char,
+    pub runs: Vec<Run>,
+}
+
+impl Check {
+    /// Answer of the main strategy, if it didn't fail
+    pub fn reference(&self) -> Option<&str> {
+        self.runs
+            .first()
+            .and_then(|r| r.outcome.as_ref().ok())
+            .map(|(answer, _)| answer.as_str())
+    }
+
+    /// Whether every strategy gave the answer of the main one
+    pub fn agrees(&self) -> bool {
+        let reference = self.reference();
+        reference.is_some()
+            && self
+                .runs
+                .iter()
+                .all(|r| r.outcome.as_ref().ok().map(|o| o.0.as_str()) == reference)
+    }
+}
+
+/// Run each of the strategies of a part `runs` times on the input
+pub fn check(strategies: &[Solution], input: &str, runs: usize) -> Check {
+    let mut strategies = strategies.to_vec();
+    strategies.sort_by_key(|s| !s.is_main());
+    Check {
+        day: strategies[0].day,
+        part: strategies[0].part,
+        runs: strategies
+            .iter()
+            .map(|s| Run {
+                strategy: s.strategy,
+                outcome: time(s, input, runs).map_err(|e| e.to_string()),
+            })
+            .collect(),
+    }
+}
+
+/// Check the parts that have alternatives and for which we have the input
+pub fn check_all(solutions: &[Solution], root: &Path, runs: usize) -> Result<Vec<Check>> {
+    let mut parts = BTreeMap::<(u32, char), Vec<Solution>>::default();
+    for solution in solutions {
+        parts
+            .entry((solution.day, solution.part))
+            .or_default()
+            .push(*solution);
+    }
+    let mut checks = vec![];
+    for ((day, _), strategies) in parts {
+        if strategies.len() < 2 {
+            continue;
+        }
+        if let Some(input) = inputs::read(root, day)? {
+            checks.push(check(&strategies, &input, runs));
+        }
+    }
+    Ok(checks)
+}
+
+/// Markdown table with one line per strategy
+pub fn table(checks: &[Check]) -> String {
+    let mut out = String::new();
+    writeln!(out, "| Day | Part | Strategy | Answer | Median | Result |").unwrap();
+    writeln!(out, "|----:|:----:|:---------|:-------|-------:|:-------|").unwrap();
+    for c in checks {
+        let reference = c.reference();
+        for (i, r) in c.runs.iter().enumerate() {
+            let strategy = if r.strategy.is_empty() {
+                "main"
+            } else {
+                r.strategy
+            };
+            let (answer, median, result) = match &r.outcome {
+                Err(e) => ("-", "-".to_string(), format!("FAILED: {}", e)),
+                Ok((answer, median)) => {
+                    let result = if i == 0 {
+                        "reference"
+                    } else if reference.is_none() {
+                        "no reference"
+                    } else if reference == Some(answer.as_str()) {
+                        "agrees"
+                    } else {
+                        "DISAGREES"
+                    };
+                    (
+                        answer.as_str(),
+                        format_duration(*median),
+                        result.to_string(),
+                    )
+                }
+            };
+            writeln!(
+                out,
+                "| {:02} | {} | {} | {} | {} | {} |",
+                c.day,
+                c.part,
+                strategy,
+                answer,
+                median,
+                result.replace('|', "\\|")
+            )
+            .unwrap();
+        }
+    }
+    let agree = checks.iter().filter(|c| c.agrees()).count();
+    writeln!(out).unwrap();
+    writeln!(out, "{} of {} parts agree.", agree, checks.len()).unwrap();
+    out
+}
+
+#[cfg(test)]
+fn strategy(
+    strategy: &'static str,
+    run: fn(&mut dyn std::io::BufRead) -> Result<String>,
+) -> Solution {
+    Solution {
+        day: 1,
+        part: 'a',
+        strategy,
+        run,
+    }
+}
+
+#[test]
+fn test_check() {
+    let len = strategy("", |bufin| {
+        Ok(std::io::read_to_string(bufin)?.len().to_string())
+    });
+    let chars = strategy("chars", |bufin| {
+        Ok(std::io::read_to_string(bufin)?.chars().count().to_string())
+    });
+    let lines = strategy("lines", |bufin| {
+        Ok(std::io::read_to_string(bufin)?.lines().count().to_string())
+    });
+    let check = check(&[chars, lines, len], "ab\n", 2);
+    assert_eq!(check.runs[0].strategy, "");
+    assert_eq!(check.reference(), Some("3"));
+    assert!(!check.agrees());
+    let table = table(&[check]);
+    assert!(table.contains("| 01 | a | main | 3 |"));
+    assert!(table.contains("| 01 | a | chars | 3 | ") && table.contains(" | agrees |"));
+    assert!(table.contains("| 01 | a | lines | 1 | ") && table.contains(" | DISAGREES |"));
+    assert!(table.ends_with("0 of 1 parts agree.\n"));
+}
+
+#[test]
+fn test_check_failed() {
+    let fail = strategy("", |_| Err(aoc::eyre!("boom")));
+    let ok = strategy("ok", |_| Ok("1".to_string()));
+    let check = check(&[fail, ok], "", 1);
+    assert_eq!(check.reference(), None);
+    assert!(!check.agrees());
+    let table = table(&[check]);
+    assert!(table.contains("| 01 | a | main | - | - | FAILED: boom |"));
+    assert!(table.contains(" | no reference |"));
+}
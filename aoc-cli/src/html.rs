@@ -0,0 +1,231 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! `aoc report --html`: one self-contained page for sharing
+//!
+//! For each day it has the answers and their status, the time of each
+//! phase as in `aoc run`, the outline of the parsed input from the day's
+//! dumper, and the picture of the days that have a visual. The
+//! pictures are inline SVG and the style is inline CSS, so that the file
+//! has no external assets.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use aoc::Solution;
+
+use crate::answers::Answers;
+use crate::cache::Cache;
+use crate::report::{self, format_duration, Status};
+use crate::{registry, run};
+
+const STYLE: &str = "body { font-family: sans-serif; background: #0f0f23; color: #cccccc; \
+                     margin: 2em; } \
+                     table { border-collapse: collapse; } \
+                     td, th { padding: 0.2em 0.8em; border-bottom: 1px solid #333340; } \
+                     code { color: #ffffff; } \
+                     .verified { color: #00cc00; } \
+                     .bad { color: #ff4444; } \
+                     figure { margin: 1em 0; }";
+
+#[derive(Debug, Clone, Default)]
+pub struct DayReport {
+    pub day: u32,
+    pub input: bool,
+    pub answers: Vec<(char, String, Status)>,
+    pub phases: Vec<(&'static str, Duration)>,
+    pub total: Duration,
+    /// The answers and phases came from the cache
+    pub cached: bool,
+    pub errors: Vec<String>,
+    /// Outline of the JSON of the parsed input
+    pub shape: Option<String>,
+    /// Title and SVG of the picture
+    pub picture: Option<(&'static str, String)>,
+}
+
+/// Solve the day and render everything that goes in its section
+pub fn day_report(
+    day: u32,
+    input: Option<&str>,
+    answers: &Answers,
+    solutions: &[Solution],
+    cache: Option<&mut Cache>,
+) -> DayReport {
+    let mut report = DayReport {
+        day,
+        ..DayReport::default()
+    };
+    let Some(input) = input else {
+        return report;
+    };
+    report.input = true;
+    match run::run_cached(day, registry::both(day), solutions, input, cache) {
+        Ok(run) => {
+            report.answers = run
+                .answers
+                .into_iter()
+                .map(|(part, answer)| {
+                    let status = report::check(answers, day, part, &answer);
+                    (part, answer, status)
+                })
+                .collect();
+            report.cached = run.cached;
+            report.total = run.phases.total();
+            report.phases = run.phases.laps;
+        }
+        Err(e) => report.errors.push(format!("solving: {}", e)),
+    }
+    if let Some(dumper) = registry::dumper(day) {
+        match (dumper.dump)(&mut input.as_bytes())
+            .and_then(|json| Ok(serde_json::from_str::<serde_json::Value>(&json)?))
+        {
+            Ok(value) => report.shape = Some(aoc::dump::shape(&value)),
+            Err(e) => report.errors.push(format!("parsing: {}", e)),
+        }
+    }
+    if let Some(visual) = registry::visual(day) {
+        match (visual.render)(&mut input.as_bytes()) {
+            Ok(canvas) => report.picture = Some((visual.title, canvas.to_svg(4))),
+            Err(e) => report.errors.push(format!("rendering: {}", e)),
+        }
+    }
+    report
+}
+
+pub fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn status_class(status: &Status) -> &'static str {
+    match status {
+        Status::Verified => "verified",
+        Status::Unverified => "",
+        _ => "bad",
+    }
+}
+
+fn section(out: &mut String, report: &DayReport) {
+    writeln!(
+        out,
+        "<h2 id=\"day{:02}\">Day {:02}</h2>",
+        report.day, report.day
+    )
+    .unwrap();
+    if !report.input {
+        writeln!(out, "<p>No input.</p>").unwrap();
+        return;
+    }
+    writeln!(out, "<ul>").unwrap();
+    for (part, answer, status) in &report.answers {
+        writeln!(
+            out,
+            "<li>Part {}: <code>{}</code> <span class=\"{}\">{}</span></li>",
+            part,
+            escape(answer),
+            status_class(status),
+            escape(&status.to_string())
+        )
+        .unwrap();
+    }
+    writeln!(out, "</ul>").unwrap();
+    if !report.answers.is_empty() {
+        let mut phases = report
+            .phases
+            .iter()
+            .map(|(name, time)| format!("{} {}", name, format_duration(*time)))
+            .collect::<Vec<_>>();
+        phases.push(format!("total {}", format_duration(report.total)));
+        if report.cached {
+            phases.push("cached".to_string());
+        }
+        writeln!(out, "<p>Phases: {}</p>", escape(&phases.join(", "))).unwrap();
+    }
+    if let Some(shape) = &report.shape {
+        writeln!(out, "<p>Parsed input: <code>{}</code></p>", escape(shape)).unwrap();
+    }
+    for error in &report.errors {
+        writeln!(out, "<p class=\"bad\">{}</p>", escape(error)).unwrap();
+    }
+    if let Some((title, svg)) = &report.picture {
+        writeln!(
+            out,
+            "<figure>{}<figcaption>{}</figcaption></figure>",
+            svg,
+            escape(title)
+        )
+        .unwrap();
+    }
+}
+
+/// The whole page
+pub fn page(reports: &[DayReport]) -> String {
+    let mut out = String::new();
+    writeln!(out, "<!DOCTYPE html>").unwrap();
+    writeln!(out, "<html><head><meta charset=\"utf-8\">").unwrap();
+    writeln!(out, "<title>Advent of Code 2024 report</title>").unwrap();
+    writeln!(out, "<style>{}</style>", STYLE).unwrap();
+    writeln!(out, "</head><body>").unwrap();
+    writeln!(out, "<h1>Advent of Code 2024 report</h1>").unwrap();
+    writeln!(
+        out,
+        "<table><tr><th>Day</th><th>Part</th><th>Answer</th><th>Status</th></tr>"
+    )
+    .unwrap();
+    for report in reports {
+        for (part, answer, status) in &report.answers {
+            writeln!(
+                out,
+                "<tr><td><a href=\"#day{:02}\">{:02}</a></td><td>{}</td>\
+                 <td><code>{}</code></td><td class=\"{}\">{}</td></tr>",
+                report.day,
+                report.day,
+                part,
+                escape(answer),
+                status_class(status),
+                escape(&status.to_string())
+            )
+            .unwrap();
+        }
+    }
+    writeln!(out, "</table>").unwrap();
+    for report in reports {
+        section(&mut out, report);
+    }
+    writeln!(out, "</body></html>").unwrap();
+    out
+}
+
+#[test]
+fn test_day_report() -> aoc::Result<()> {
+    let answers = Answers::parse("day06a 41\n")?;
+    // The example is 10 by 10
+    let solutions = [Solution {
+        day: 6,
+        part: 'a',
+        strategy: "",
+        run: |bufin| Ok(day06::a::process(bufin, 10)?.to_string()),
+    }];
+    let report = day_report(6, Some(day06::EXAMPLE), &answers, &solutions, None);
+    assert_eq!(report.errors, Vec::<String>::new());
+    assert_eq!(report.answers[0].2, Status::Verified);
+    assert_eq!(
+        report.shape.as_deref(),
+        Some("[10 × [10 × [string, bool]]]")
+    );
+    let page = page(&[report, day_report(7, None, &answers, &[], None)]);
+    assert!(page.contains("<td class=\"verified\">verified</td>"));
+    assert!(page.contains("<figure><svg"));
+    assert!(page.contains("<h2 id=\"day07\">Day 07</h2>\n<p>No input.</p>"));
+    assert!(!page.contains("src="));
+    Ok(())
+}
+
+#[test]
+fn test_escape() {
+    assert_eq!(escape("<a & \"b\">"), "&lt;a &amp; &quot;b&quot;&gt;");
+}
@@ -0,0 +1,225 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Just enough HTTP/1.1 to serve local tools: one request per
+//! connection, with the body delimited by `Content-Length`
+
+use std::fmt;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpStream;
+use std::time::Duration;
+
+use aoc::{eyre, Result};
+
+/// Largest body we accept; inputs are tens of kilobytes
+pub const MAX_BODY: usize = 1 << 20;
+
+/// Time a client has to send each part of its request
+pub const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Error of a request with a body larger than [`MAX_BODY`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BodyTooLarge(pub usize);
+
+impl fmt::Display for BodyTooLarge {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "body of {} bytes, the limit is {}", self.0, MAX_BODY)
+    }
+}
+
+impl std::error::Error for BodyTooLarge {}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    /// Header names in lowercase
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+impl Request {
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Value of a cookie in the `Cookie` header
+    pub fn cookie(&self, name: &str) -> Option<&str> {
+        self.header("cookie")?.split(';').find_map(|c| {
+            let (n, v) = c.trim().split_once('=')?;
+            (n == name).then_some(v)
+        })
+    }
+
+    /// Value of a field of an `application/x-www-form-urlencoded` body
+    pub fn form(&self, name: &str) -> Option<String> {
+        let body = std::str::from_utf8(&self.body).ok()?;
+        body.split('&').find_map(|f| {
+            let (n, v) = f.split_once('=')?;
+            (n == name).then(|| url_decode(v))
+        })
+    }
+}
+
+fn url_decode(value: &str) -> String {
+    let mut bytes = vec![];
+    let mut it = value.bytes();
+    while let Some(b) = it.next() {
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = [it.next().unwrap_or(b'0'), it.next().unwrap_or(b'0')];
+                let hex = std::str::from_utf8(&hex).unwrap_or("00");
+                bytes.push(u8::from_str_radix(hex, 16).unwrap_or(b'?'));
+            }
+            b => bytes.push(b),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+pub fn read_request(stream: &mut impl BufRead) -> Result<Request> {
+    let mut line = String::new();
+    stream.read_line(&mut line)?;
+    let mut fields = line.split_whitespace();
+    let (Some(method), Some(path)) = (fields.next(), fields.next()) else {
+        return Err(eyre!("invalid request line {:?}", line));
+    };
+    let mut request = Request {
+        method: method.to_string(),
+        path: path.to_string(),
+        ..Request::default()
+    };
+    loop {
+        line.clear();
+        stream.read_line(&mut line)?;
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        let (name, value) = header
+            .split_once(':')
+            .ok_or_else(|| eyre!("invalid header {:?}", header))?;
+        request
+            .headers
+            .push((name.trim().to_lowercase(), value.trim().to_string()));
+    }
+    let len = request
+        .header("content-length")
+        .map_or(Ok(0), |l| l.parse::<usize>())?;
+    if len > MAX_BODY {
+        return Err(BodyTooLarge(len).into());
+    }
+    request.body = vec![0; len];
+    stream.read_exact(&mut request.body)?;
+    Ok(request)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub status: u16,
+    pub content_type: &'static str,
+    pub body: Vec<u8>,
+}
+
+impl Response {
+    pub fn new(status: u16, content_type: &'static str, body: impl Into<Vec<u8>>) -> Response {
+        Response {
+            status,
+            content_type,
+            body: body.into(),
+        }
+    }
+
+    pub fn text(status: u16, body: impl Into<Vec<u8>>) -> Response {
+        Response::new(status, "text/plain", body)
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Content Too Large",
+        422 => "Unprocessable Content",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "Unknown",
+    }
+}
+
+pub fn write_response(stream: &mut impl Write, response: &Response) -> Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status,
+        reason(response.status),
+        response.content_type,
+        response.body.len()
+    )?;
+    stream.write_all(&response.body)?;
+    stream.flush()?;
+    Ok(())
+}
+
+/// Answer the request of the connection with `handle`, or with a 413 if
+/// its body is too large
+pub fn serve_connection(
+    stream: TcpStream,
+    handle: impl FnOnce(&Request) -> Response,
+) -> Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let response = match read_request(&mut reader) {
+        Ok(request) => handle(&request),
+        Err(e) => match e.downcast_ref::<BodyTooLarge>() {
+            Some(too_large) => Response::text(413, too_large.to_string()),
+            None => return Err(e),
+        },
+    };
+    write_response(&mut &stream, &response)
+}
+
+#[test]
+fn test_read_request() -> Result<()> {
+    let raw = "POST /2024/day/6/answer HTTP/1.1\r\n\
+               Host: localhost\r\n\
+               Cookie: a=1; session=abc\r\n\
+               Content-Length: 22\r\n\
+               \r\n\
+               level=1&answer=4%2C6+3";
+    let request = read_request(&mut raw.as_bytes())?;
+    assert_eq!(request.method, "POST");
+    assert_eq!(request.path, "/2024/day/6/answer");
+    assert_eq!(request.header("host"), Some("localhost"));
+    assert_eq!(request.cookie("session"), Some("abc"));
+    assert_eq!(request.form("level").as_deref(), Some("1"));
+    assert_eq!(request.form("answer").as_deref(), Some("4,6 3"));
+    assert!(read_request(&mut "\r\n".as_bytes()).is_err());
+    let huge = "POST / HTTP/1.1\r\nContent-Length: 99999999999999\r\n\r\n";
+    let report = read_request(&mut huge.as_bytes()).unwrap_err();
+    assert_eq!(
+        report.downcast_ref::<BodyTooLarge>(),
+        Some(&BodyTooLarge(99999999999999))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_write_response() -> Result<()> {
+    let mut out = vec![];
+    write_response(&mut out, &Response::text(404, "nope"))?;
+    assert_eq!(
+        String::from_utf8(out)?,
+        "HTTP/1.1 404 Not Found\r\nContent-Type: text/plain\r\n\
+         Content-Length: 4\r\nConnection: close\r\n\r\nnope"
+    );
+    Ok(())
+}
@@ -0,0 +1,24 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Puzzle inputs, kept out of git at `dayNN/input.txt`
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc::Result;
+
+pub fn path(root: &Path, day: u32) -> PathBuf {
+    root.join(format!("day{:02}", day)).join("input.txt")
+}
+
+/// Contents of the input of the day, None if we don't have it
+pub fn read(root: &Path, day: u32) -> Result<Option<String>> {
+    match fs::read_to_string(path(root, day)) {
+        Ok(input) => Ok(Some(input)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
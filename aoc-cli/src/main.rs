@@ -0,0 +1,490 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! `aoc`: workspace tool that runs the registered solutions
+
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use aoc::{eyre, Result};
+
+mod answers;
+mod cache;
+mod client;
+mod crosscheck;
+mod html;
+mod http;
+mod inputs;
+mod minimize;
+mod mock;
+mod registry;
+mod report;
+mod run;
+mod serve;
+mod watch;
+
+#[derive(Parser, Debug)]
+#[command(about = "Advent of Code 2024 workspace tool")]
+struct Cli {
+    /// Workspace directory, with the dayNN/input.txt files
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+    /// Database of known answers [default: <root>/answers.txt]
+    #[arg(long)]
+    answers: Option<PathBuf>,
+    /// URL of the puzzle site [default: $AOC_BASE_URL or the real one]
+    #[arg(long)]
+    base_url: Option<String>,
+    /// Neither use nor update the cache of answers of `run` and `report`
+    #[arg(long)]
+    no_cache: bool,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Run every solution and print a Markdown report
+    Report {
+        /// Number of runs of each solution; the median is reported
+        #[arg(long, default_value_t = 3)]
+        runs: usize,
+        /// Number of days in the histogram of the slowest ones
+        #[arg(long, default_value_t = 10)]
+        top: usize,
+        /// Instead, run every strategy of the parts that have
+        /// alternatives and report any disagreement
+        #[arg(long, conflicts_with_all = ["html", "top"])]
+        cross_check: bool,
+        /// Instead, write a self-contained HTML page with the answers,
+        /// phase timings, parsed-input outline and pictures of the days
+        #[arg(long, conflicts_with_all = ["runs", "top"])]
+        html: Option<PathBuf>,
+        /// Days in the HTML page [default: all]
+        #[arg(long, value_delimiter = ',', requires = "html")]
+        days: Vec<u32>,
+    },
+    /// Solve both parts of a day and print the time of each phase
+    Run {
+        day: u32,
+        /// Input file [default: <root>/dayNN/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// The input file has the JSON printed by `aoc dump`
+        #[arg(long)]
+        json: bool,
+        /// Continue the searches of day17b and day24b from their last
+        /// checkpoint, saved periodically and on Ctrl-C
+        #[arg(long)]
+        resume: bool,
+    },
+    /// Print the parsed input of a day as JSON
+    Dump {
+        day: u32,
+        /// Input file [default: <root>/dayNN/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Rebuild, test and run a day whenever its code or input change
+    Watch {
+        day: u32,
+        /// Input file [default: <root>/dayNN/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Shrink an input while it still makes a part fail, and print it
+    Minimize {
+        day: u32,
+        part: char,
+        file: PathBuf,
+        #[arg(long, value_enum, default_value_t = minimize::Failure::Crash)]
+        failure: minimize::Failure,
+        /// Seconds after which a run counts as hung
+        #[arg(long, default_value_t = 10)]
+        timeout: u64,
+    },
+    /// Solve a part with the input from stdin, used by `aoc minimize`
+    #[command(hide = true)]
+    Solve {
+        day: u32,
+        part: char,
+        #[arg(long, default_value = "")]
+        strategy: String,
+    },
+    /// Check that an input is valid for a day, listing every problem
+    Check { day: u32, file: PathBuf },
+    /// Print an input with its labels renamed, after checking that it
+    /// has the answers it should
+    Anonymize {
+        day: u32,
+        /// Input file [default: <root>/dayNN/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Download the input of a day to <root>/dayNN/input.txt
+    Fetch {
+        day: u32,
+        /// Download it even if we already have it
+        #[arg(long)]
+        force: bool,
+    },
+    /// Submit an answer, by default the one of our solution
+    Submit {
+        day: u32,
+        part: char,
+        answer: Option<String>,
+    },
+    /// Serve our inputs and answers like the puzzle site does
+    MockServer {
+        #[arg(long, default_value_t = 8024)]
+        port: u16,
+        /// Session token the server accepts
+        #[arg(long, default_value = "mock")]
+        session: String,
+        /// Seconds refusing answers after a wrong one
+        #[arg(long, default_value_t = 60)]
+        cooldown: u64,
+    },
+    /// Solve the inputs POSTed to /day/<day>/<part>, answering with JSON
+    Serve {
+        #[arg(long, default_value_t = 8025)]
+        port: u16,
+        /// Maximum number of solves running at the same time
+        #[arg(long, default_value_t = 4)]
+        concurrency: usize,
+        /// Seconds a request waits for its answer
+        #[arg(long, default_value_t = 30)]
+        timeout: u64,
+    },
+    /// Print a random input for a day that has a generator
+    Generate {
+        day: u32,
+        /// Number of elements: lines, stones, blocks, etc.
+        #[arg(long, default_value_t = 10)]
+        size: usize,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+}
+
+fn client(cli: &Cli) -> Result<client::Client> {
+    let base_url = cli
+        .base_url
+        .clone()
+        .or_else(|| std::env::var("AOC_BASE_URL").ok())
+        .unwrap_or_else(|| client::BASE_URL.to_string());
+    Ok(client::Client::new(&base_url, &client::session(&cli.root)?))
+}
+
+fn main() {
+    let result = color_eyre::install().and_then(|()| execute(Cli::parse()));
+    std::process::exit(exit_status(result));
+}
+
+/// Exit status of `aoc`: the one of the PuzzleError that ended it, as in
+/// the binaries of the days, 1 for other errors, or 0
+fn exit_status(result: Result<()>) -> i32 {
+    match result {
+        Ok(()) => 0,
+        Err(report) => {
+            eprintln!("Error: {:?}", report);
+            aoc::exit_code(&report)
+        }
+    }
+}
+
+fn execute(cli: Cli) -> Result<()> {
+    let answers_path = cli
+        .answers
+        .clone()
+        .unwrap_or_else(|| cli.root.join("answers.txt"));
+    let answers = answers::Answers::load(&answers_path)?;
+    let mut cache = (!cli.no_cache).then(|| cache::Cache::load(&cache::path(&cli.root)));
+    match cli.command {
+        Command::Report {
+            runs,
+            cross_check: true,
+            ..
+        } => {
+            let checks = crosscheck::check_all(&registry::solutions(), &cli.root, runs)?;
+            print!("{}", crosscheck::table(&checks));
+            let disagree = checks.iter().filter(|c| !c.agrees()).count();
+            if disagree > 0 {
+                return Err(eyre!("{} parts disagree", disagree));
+            }
+        }
+        Command::Report {
+            html: Some(path),
+            days,
+            ..
+        } => {
+            let solutions = registry::solutions();
+            let days = if days.is_empty() {
+                (1..=25).collect()
+            } else {
+                days
+            };
+            let mut reports = vec![];
+            for day in days {
+                let input = inputs::read(&cli.root, day)?;
+                reports.push(html::day_report(
+                    day,
+                    input.as_deref(),
+                    &answers,
+                    &solutions,
+                    cache.as_mut(),
+                ));
+            }
+            std::fs::write(&path, html::page(&reports))
+                .map_err(|e| eyre!("writing {}: {}", path.display(), e))?;
+            println!("{}", path.display());
+        }
+        Command::Report { runs, top, .. } => {
+            let entries = report::run_all(
+                &registry::solutions(),
+                &cli.root,
+                &answers,
+                runs,
+                cache.as_mut(),
+            )?;
+            println!("{}", report::table(&entries));
+            print!("{}", report::histogram(&entries, top, 40));
+        }
+        Command::Run {
+            day,
+            input,
+            json,
+            resume,
+        } => {
+            aoc::checkpoint::enable(&cli.root.join("target").join("checkpoints"), resume)?;
+            let path = input.unwrap_or_else(|| inputs::path(&cli.root, day));
+            let mut input = std::fs::read_to_string(&path)
+                .map_err(|e| eyre!("reading {}: {}", path.display(), e))?;
+            if json {
+                let dumper = registry::dumper(day).ok_or_else(|| eyre!("no day {}", day))?;
+                input = (dumper.load)(&input)?;
+            }
+            let run = run::run_cached(
+                day,
+                registry::both(day),
+                &registry::solutions(),
+                &input,
+                cache.as_mut(),
+            )?;
+            if run.answers.is_empty() {
+                return Err(eyre!("day {} has no solutions", day));
+            }
+            print!("{}", run.format());
+        }
+        Command::Dump { day, input } => {
+            let dumper = registry::dumper(day).ok_or_else(|| eyre!("no day {}", day))?;
+            let path = input.unwrap_or_else(|| inputs::path(&cli.root, day));
+            let input = std::fs::read_to_string(&path)
+                .map_err(|e| eyre!("reading {}: {}", path.display(), e))?;
+            print!("{}", (dumper.dump)(&mut input.as_bytes())?);
+        }
+        Command::Watch { day, input } => {
+            let path = input.unwrap_or_else(|| inputs::path(&cli.root, day));
+            watch::watch(&cli.root, day, &path)?;
+        }
+        Command::Minimize {
+            day,
+            part,
+            file,
+            failure,
+            timeout,
+        } => {
+            let input = std::fs::read_to_string(&file)
+                .map_err(|e| eyre!("reading {}: {}", file.display(), e))?;
+            let mut minimizer = minimize::Minimizer {
+                exe: std::env::current_exe()?,
+                day,
+                part,
+                strategies: registry::solutions()
+                    .into_iter()
+                    .filter(|s| s.day == day && s.part == part)
+                    .map(|s| s.strategy)
+                    .collect(),
+                dumper: registry::dumper(day).ok_or_else(|| eyre!("no day {}", day))?,
+                validator: registry::validator(day),
+                failure,
+                timeout: std::time::Duration::from_secs(timeout),
+                runs: 0,
+            };
+            if minimizer.strategies.is_empty() {
+                return Err(eyre!("no solution for day {} part {}", day, part));
+            }
+            let minimal = minimizer.minimize(&input)?;
+            eprintln!(
+                "{} lines -> {} lines, {} runs",
+                input.lines().count(),
+                minimal.lines().count(),
+                minimizer.runs
+            );
+            print!("{}", minimal);
+        }
+        Command::Solve {
+            day,
+            part,
+            strategy,
+        } => {
+            let solution = registry::solutions()
+                .into_iter()
+                .find(|s| s.day == day && s.part == part && s.strategy == strategy)
+                .ok_or_else(|| eyre!("no solution day{:02}{} {:?}", day, part, strategy))?;
+            println!("{}", (solution.run)(&mut std::io::stdin().lock())?);
+        }
+        Command::Check { day, file } => {
+            let validator =
+                registry::validator(day).ok_or_else(|| eyre!("day {} has no validator", day))?;
+            let input = std::fs::read_to_string(&file)
+                .map_err(|e| eyre!("reading {}: {}", file.display(), e))?;
+            let violations = (validator.run)(&mut input.as_bytes())?;
+            for violation in violations.iter() {
+                println!("{}: {}", file.display(), violation);
+            }
+            if !violations.is_empty() {
+                return Err(eyre!("{} problems found", violations.len()));
+            }
+            println!("{}: ok", file.display());
+        }
+        Command::Anonymize { day, input, seed } => {
+            let anonymizer =
+                registry::anonymizer(day).ok_or_else(|| eyre!("day {} has no anonymizer", day))?;
+            let path = input.unwrap_or_else(|| inputs::path(&cli.root, day));
+            let input = std::fs::read_to_string(&path)
+                .map_err(|e| eyre!("reading {}: {}", path.display(), e))?;
+            let (anonymized, labels) = anonymizer.anonymize(&input, seed)?;
+            let checks = anonymizer.check(&registry::solutions(), &input, &anonymized, &labels)?;
+            for check in &checks {
+                eprintln!("day{:02}{}", day, check);
+            }
+            if checks.iter().any(|check| !check.ok()) {
+                return Err(eyre!("the anonymized input has other answers"));
+            }
+            print!("{}", anonymized);
+        }
+        Command::Fetch { day, force } => {
+            let path = inputs::path(&cli.root, day);
+            if path.exists() && !force {
+                println!("{}: already fetched", path.display());
+                return Ok(());
+            }
+            let input = client(&cli)?.input(day)?;
+            std::fs::write(&path, input)?;
+            println!("{}: fetched", path.display());
+        }
+        Command::Submit {
+            day,
+            part,
+            ref answer,
+        } => {
+            let answer = match answer {
+                Some(answer) => answer.clone(),
+                None => {
+                    let input = inputs::read(&cli.root, day)?
+                        .ok_or_else(|| eyre!("no input for day {}", day))?;
+                    let solution = registry::solutions()
+                        .into_iter()
+                        .find(|s| s.day == day && s.part == part && s.is_main())
+                        .ok_or_else(|| eyre!("no solution for day {} part {}", day, part))?;
+                    (solution.run)(&mut input.as_bytes())?
+                }
+            };
+            if answers.get(day, part) == Some(answer.as_str()) {
+                println!("{}: already known to be correct", answer);
+                return Ok(());
+            }
+            let outcome = client(&cli)?.submit(day, part, &answer)?;
+            println!("{}: {}", answer, outcome);
+            if outcome == client::Outcome::Correct {
+                answers::Answers::append(&answers_path, day, part, &answer)?;
+            }
+        }
+        Command::MockServer {
+            port,
+            session,
+            cooldown,
+        } => {
+            let mut site = mock::Site {
+                session,
+                answers: answers.iter().map(|(k, v)| (*k, v.clone())).collect(),
+                cooldown: std::time::Duration::from_secs(cooldown),
+                ..mock::Site::default()
+            };
+            for day in 1..=25 {
+                if let Some(input) = inputs::read(&cli.root, day)? {
+                    site.inputs.insert(day, input);
+                }
+            }
+            let server = mock::MockServer::start(site, port)?;
+            println!("Serving on {}", server.url());
+            loop {
+                std::thread::park();
+            }
+        }
+        Command::Serve {
+            port,
+            concurrency,
+            timeout,
+        } => {
+            let config = serve::Config {
+                concurrency,
+                timeout: std::time::Duration::from_secs(timeout),
+            };
+            let server = serve::Server::start(registry::solutions(), config, port)?;
+            println!("Serving on {}", server.url());
+            loop {
+                std::thread::park();
+            }
+        }
+        Command::Generate { day, size, seed } => {
+            let generator = registry::generator(day)
+                .ok_or_else(|| eyre!("day {} has no input generator", day))?;
+            print!("{}", generator.generate_seeded(seed, size));
+        }
+    }
+    if let Some(cache) = &mut cache {
+        cache.save()?;
+    }
+    Ok(())
+}
+
+#[test]
+fn test_report_conflicts() {
+    let parse = |args: &[&str]| Cli::try_parse_from([&["aoc", "report"], args].concat());
+    assert!(parse(&["--html", "x", "--days", "6"]).is_ok());
+    assert!(parse(&["--cross-check", "--runs", "1"]).is_ok());
+    assert!(parse(&["--cross-check", "--html", "x"]).is_err());
+    assert!(parse(&["--html", "x", "--runs", "1"]).is_err());
+    assert!(parse(&["--days", "6"]).is_err());
+}
+
+#[test]
+fn test_exit_status() -> Result<()> {
+    assert_eq!(exit_status(Ok(())), 0);
+    assert_eq!(exit_status(Err(eyre!("other"))), 1);
+    let interrupted = aoc::Report::new(aoc::PuzzleError::interrupted("state saved"));
+    assert_eq!(exit_status(Err(interrupted.wrap_err("day17b"))), 130);
+    let dir = std::env::temp_dir().join(format!("aoc-exit-status-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+    let input = dir.join("input.txt");
+    std::fs::write(&input, "1 x\n")?;
+    let cli = Cli::try_parse_from([
+        "aoc",
+        "--root",
+        &dir.to_string_lossy(),
+        "--no-cache",
+        "run",
+        "1",
+        "--input",
+        &input.to_string_lossy(),
+    ])?;
+    assert_eq!(exit_status(execute(cli)), 2);
+    std::fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,159 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! `aoc minimize`: smallest input that still makes a part fail
+//!
+//! The input is reduced with [`aoc::minimize`], keeping only candidates
+//! that the day's parser accepts, validator included. Each candidate
+//! runs in a child `aoc solve`, so that panics, stack overflows and
+//! endless loops don't take us down with them.
+
+use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::process::{Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::dump::Dumper;
+use aoc::validate::Validator;
+use aoc::{eyre, Result};
+
+/// What makes an input a reproducer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Failure {
+    /// The main strategy panics or aborts
+    Crash,
+    /// The main strategy returns an error
+    Error,
+    /// The main strategy doesn't finish before the timeout
+    Hang,
+    /// The strategies of the part don't all give the same result
+    Disagree,
+}
+
+/// Result of running a solution in a child process
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Answer(String),
+    Error,
+    Crash,
+    Hang,
+}
+
+/// Outcome of a child that finished; panics exit with 101
+pub fn classify(status: ExitStatus, stdout: &str) -> Outcome {
+    match status.code() {
+        Some(0) => Outcome::Answer(stdout.trim().to_string()),
+        Some(101) | None => Outcome::Crash,
+        Some(_) => Outcome::Error,
+    }
+}
+
+#[derive(Debug)]
+pub struct Minimizer {
+    /// The `aoc` binary that runs `aoc solve`
+    pub exe: PathBuf,
+    pub day: u32,
+    pub part: char,
+    /// Strategies of the part, the main one first
+    pub strategies: Vec<&'static str>,
+    pub dumper: Dumper,
+    pub validator: Option<Validator>,
+    pub failure: Failure,
+    pub timeout: Duration,
+    /// Number of candidates that were run
+    pub runs: usize,
+}
+
+impl Minimizer {
+    pub fn solve(&self, strategy: &str, input: &str) -> Result<Outcome> {
+        let mut child = Command::new(&self.exe)
+            .args(["solve", &self.day.to_string(), &self.part.to_string()])
+            .args(["--strategy", strategy])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()?;
+        // A child that crashes early doesn't read all of it, which is fine
+        let _ = child.stdin.take().unwrap().write_all(input.as_bytes());
+        let start = Instant::now();
+        let status = loop {
+            if let Some(status) = child.try_wait()? {
+                break status;
+            }
+            if start.elapsed() > self.timeout {
+                child.kill()?;
+                child.wait()?;
+                return Ok(Outcome::Hang);
+            }
+            thread::sleep(Duration::from_millis(5));
+        };
+        let mut stdout = String::new();
+        child.stdout.take().unwrap().read_to_string(&mut stdout)?;
+        Ok(classify(status, &stdout))
+    }
+
+    /// Whether the day's parser accepts the input
+    pub fn parses(&self, input: &str) -> bool {
+        (self.dumper.dump)(&mut input.as_bytes()).is_ok()
+            && self.validator.is_none_or(|validator| {
+                (validator.run)(&mut input.as_bytes()).is_ok_and(|v| v.is_empty())
+            })
+    }
+
+    pub fn fails(&mut self, input: &str) -> Result<bool> {
+        if !self.parses(input) {
+            return Ok(false);
+        }
+        self.runs += 1;
+        let main = self.solve(self.strategies[0], input)?;
+        Ok(match self.failure {
+            Failure::Crash => main == Outcome::Crash,
+            Failure::Error => main == Outcome::Error,
+            Failure::Hang => main == Outcome::Hang,
+            Failure::Disagree => {
+                for strategy in &self.strategies[1..] {
+                    if self.solve(strategy, input)? != main {
+                        return Ok(true);
+                    }
+                }
+                false
+            }
+        })
+    }
+
+    /// Minimal input that still fails; errors if `input` itself doesn't
+    pub fn minimize(&mut self, input: &str) -> Result<String> {
+        if self.failure == Failure::Disagree && self.strategies.len() < 2 {
+            return Err(eyre!(
+                "day{:02}{} has a single strategy",
+                self.day,
+                self.part
+            ));
+        }
+        if !self.parses(input) {
+            return Err(eyre!("the input doesn't parse"));
+        }
+        if !self.fails(input)? {
+            return Err(eyre!("the input doesn't reproduce the failure"));
+        }
+        Ok(aoc::minimize::minimize(input, |candidate| {
+            self.fails(candidate).unwrap_or(false)
+        }))
+    }
+}
+
+#[test]
+fn test_classify() {
+    use std::os::unix::process::ExitStatusExt;
+    let status = ExitStatus::from_raw;
+    assert_eq!(
+        classify(status(0), "42\n"),
+        Outcome::Answer("42".to_string())
+    );
+    assert_eq!(classify(status(1 << 8), ""), Outcome::Error);
+    assert_eq!(classify(status(101 << 8), ""), Outcome::Crash);
+    // Killed by SIGABRT, like on a stack overflow
+    assert_eq!(classify(status(6), ""), Outcome::Crash);
+}
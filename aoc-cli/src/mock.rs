@@ -0,0 +1,201 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Local stand-in of the puzzle site, for using and testing the
+//! [`client`](crate::client) offline
+//!
+//! It serves the inputs and checks the answers it was given, answering
+//! with pages that have the same sentences as the real ones, including
+//! the hints and the cooldown after a wrong answer.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::Result;
+
+use crate::client::YEAR;
+use crate::http::{self, Request, Response};
+
+#[derive(Debug, Clone, Default)]
+pub struct Site {
+    pub session: String,
+    pub inputs: BTreeMap<u32, String>,
+    pub answers: BTreeMap<(u32, char), String>,
+    /// Time after a wrong answer during which others are refused
+    pub cooldown: Duration,
+}
+
+#[derive(Debug, Default)]
+struct State {
+    solved: BTreeSet<(u32, char)>,
+    last_wrong: Option<Instant>,
+}
+
+fn page(text: &str) -> Response {
+    Response::new(
+        200,
+        "text/html",
+        format!(
+            "<!DOCTYPE html>\n<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>\n",
+            text
+        ),
+    )
+}
+
+/// Day of a `/2024/day/<day>/<what>` path
+fn parse_path(path: &str) -> Option<(u32, &str)> {
+    let rest = path.strip_prefix(&format!("/{}/day/", YEAR))?;
+    let (day, what) = rest.split_once('/')?;
+    Some((day.parse().ok()?, what))
+}
+
+fn handle(site: &Site, state: &mut State, request: &Request) -> Response {
+    let Some((day, what)) = parse_path(&request.path) else {
+        return Response::text(404, "404 Not Found");
+    };
+    if request.cookie("session") != Some(site.session.as_str()) {
+        return Response::text(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+        );
+    }
+    match (request.method.as_str(), what) {
+        ("GET", "input") => match site.inputs.get(&day) {
+            Some(input) => Response::text(200, input.clone()),
+            None => Response::text(404, "404 Not Found"),
+        },
+        ("POST", "answer") => {
+            let part = match request.form("level").as_deref() {
+                Some("1") => 'a',
+                Some("2") => 'b',
+                _ => return Response::text(400, "Bad level"),
+            };
+            let answer = request.form("answer").unwrap_or_default();
+            submit(site, state, day, part, answer.trim())
+        }
+        _ => Response::text(405, "Method Not Allowed"),
+    }
+}
+
+fn submit(site: &Site, state: &mut State, day: u32, part: char, answer: &str) -> Response {
+    let Some(expected) = site.answers.get(&(day, part)) else {
+        return Response::text(404, "404 Not Found");
+    };
+    if state.solved.contains(&(day, part)) || (part == 'b' && !state.solved.contains(&(day, 'a'))) {
+        return page("You don't seem to be solving the right level.  Did you already complete it?");
+    }
+    if let Some(left) = state
+        .last_wrong
+        .and_then(|t| site.cooldown.checked_sub(t.elapsed()))
+    {
+        return page(&format!(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have {}s left to wait.",
+            left.as_secs() + 1
+        ));
+    }
+    if answer == expected {
+        state.solved.insert((day, part));
+        state.last_wrong = None;
+        return page("That's the right answer!  You are <span>one gold star</span> closer.");
+    }
+    state.last_wrong = Some(Instant::now());
+    let hint = match (answer.parse::<i128>(), expected.parse::<i128>()) {
+        (Ok(a), Ok(e)) if a > e => "; your answer is too high",
+        (Ok(a), Ok(e)) if a < e => "; your answer is too low",
+        _ => "",
+    };
+    page(&format!(
+        "That's not the right answer{}.  If you're stuck, make sure you're using the full \
+         input data.",
+        hint
+    ))
+}
+
+#[derive(Debug)]
+pub struct MockServer {
+    pub addr: SocketAddr,
+}
+
+impl MockServer {
+    /// Listen on `127.0.0.1:port` and serve the site from a thread; port
+    /// 0 picks a free one
+    pub fn start(site: Site, port: u16) -> Result<MockServer> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        let addr = listener.local_addr()?;
+        let site = Arc::new(site);
+        let state = Arc::new(Mutex::new(State::default()));
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let served = http::serve_connection(stream, |request| {
+                    handle(&site, &mut state.lock().unwrap(), request)
+                });
+                if let Err(e) = served {
+                    eprintln!("mock: {}", e);
+                }
+            }
+        });
+        Ok(MockServer { addr })
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+}
+
+#[cfg(test)]
+fn site(cooldown: Duration) -> Site {
+    Site {
+        session: "s3cr3t".to_string(),
+        inputs: [(6, "....#\n".to_string())].into_iter().collect(),
+        answers: [((6, 'a'), "41".to_string()), ((6, 'b'), "6".to_string())]
+            .into_iter()
+            .collect(),
+        cooldown,
+    }
+}
+
+#[test]
+fn test_fetch() -> Result<()> {
+    use crate::client::Client;
+    let server = MockServer::start(site(Duration::ZERO), 0)?;
+    let client = Client::new(&server.url(), "s3cr3t");
+    assert_eq!(client.input(6)?, "....#\n");
+    assert!(client.input(7).is_err());
+    assert!(Client::new(&server.url(), "wrong").input(6).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_submit() -> Result<()> {
+    use crate::client::{Client, Outcome};
+    let server = MockServer::start(site(Duration::ZERO), 0)?;
+    let client = Client::new(&server.url(), "s3cr3t");
+    assert_eq!(client.submit(6, 'b', "6")?, Outcome::WrongLevel);
+    assert_eq!(client.submit(6, 'a', "42")?, Outcome::TooHigh);
+    assert_eq!(client.submit(6, 'a', "40")?, Outcome::TooLow);
+    assert_eq!(client.submit(6, 'a', "x")?, Outcome::Wrong);
+    assert_eq!(client.submit(6, 'a', "41")?, Outcome::Correct);
+    assert_eq!(client.submit(6, 'a', "41")?, Outcome::WrongLevel);
+    assert_eq!(client.submit(6, 'b', "6")?, Outcome::Correct);
+    Ok(())
+}
+
+#[test]
+fn test_rate_limit() -> Result<()> {
+    use crate::client::{Client, Outcome};
+    let server = MockServer::start(site(Duration::from_secs(60)), 0)?;
+    let client = Client::new(&server.url(), "s3cr3t");
+    assert_eq!(client.submit(6, 'a', "1")?, Outcome::TooLow);
+    assert_eq!(
+        client.submit(6, 'a', "41")?,
+        Outcome::RateLimited {
+            wait: Some("60s".to_string())
+        }
+    );
+    Ok(())
+}
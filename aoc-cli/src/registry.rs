@@ -0,0 +1,191 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! All the solutions of the workspace
+
+use aoc::anonymize::Anonymizer;
+use aoc::dump::Dumper;
+use aoc::generator::Generator;
+use aoc::svg::Visual;
+use aoc::validate::Validator;
+use aoc::{Both, Solution};
+
+/// Every registered solution, ordered by day and part, with the
+/// alternative strategies after the main one
+pub fn solutions() -> Vec<Solution> {
+    [
+        day01::SOLUTIONS,
+        day02::SOLUTIONS,
+        day03::SOLUTIONS,
+        day04::SOLUTIONS,
+        day05::SOLUTIONS,
+        day06::SOLUTIONS,
+        day07::SOLUTIONS,
+        day08::SOLUTIONS,
+        day09::SOLUTIONS,
+        day10::SOLUTIONS,
+        day11::SOLUTIONS,
+        day12::SOLUTIONS,
+        day13::SOLUTIONS,
+        day14::SOLUTIONS,
+        day15::SOLUTIONS,
+        day16::SOLUTIONS,
+        day17::SOLUTIONS,
+        day18::SOLUTIONS,
+        day19::SOLUTIONS,
+        day20::SOLUTIONS,
+        day21::SOLUTIONS,
+        day22::SOLUTIONS,
+        day23::SOLUTIONS,
+        day24::SOLUTIONS,
+        day25::SOLUTIONS,
+    ]
+    .concat()
+}
+
+/// Single-parse solver of both parts of the day, if it has one
+pub fn both(day: u32) -> Option<Both> {
+    match day {
+        9 => Some(day09::BOTH),
+        16 => Some(day16::BOTH),
+        20 => Some(day20::BOTH),
+        _ => None,
+    }
+}
+
+/// Input generator of the day, if it has one
+pub fn generator(day: u32) -> Option<&'static dyn Generator> {
+    match day {
+        1 => Some(day01::GENERATOR),
+        6 => Some(day06::GENERATOR),
+        7 => Some(day07::GENERATOR),
+        9 => Some(day09::GENERATOR),
+        11 => Some(day11::GENERATOR),
+        _ => None,
+    }
+}
+
+/// Semantic checks of the input of the day, if it has them
+pub fn validator(day: u32) -> Option<Validator> {
+    match day {
+        6 => Some(day06::VALIDATOR),
+        16 => Some(day16::VALIDATOR),
+        20 => Some(day20::VALIDATOR),
+        24 => Some(day24::VALIDATOR),
+        25 => Some(day25::VALIDATOR),
+        _ => None,
+    }
+}
+
+/// JSON form of the parsed input of the day
+pub fn dumper(day: u32) -> Option<Dumper> {
+    match day {
+        1 => Some(day01::DUMPER),
+        2 => Some(day02::DUMPER),
+        3 => Some(day03::DUMPER),
+        4 => Some(day04::DUMPER),
+        5 => Some(day05::DUMPER),
+        6 => Some(day06::DUMPER),
+        7 => Some(day07::DUMPER),
+        8 => Some(day08::DUMPER),
+        9 => Some(day09::DUMPER),
+        10 => Some(day10::DUMPER),
+        11 => Some(day11::DUMPER),
+        12 => Some(day12::DUMPER),
+        13 => Some(day13::DUMPER),
+        14 => Some(day14::DUMPER),
+        15 => Some(day15::DUMPER),
+        16 => Some(day16::DUMPER),
+        17 => Some(day17::DUMPER),
+        18 => Some(day18::DUMPER),
+        19 => Some(day19::DUMPER),
+        20 => Some(day20::DUMPER),
+        21 => Some(day21::DUMPER),
+        22 => Some(day22::DUMPER),
+        23 => Some(day23::DUMPER),
+        24 => Some(day24::DUMPER),
+        25 => Some(day25::DUMPER),
+        _ => None,
+    }
+}
+
+/// Picture of the input and solution of the day, if it has one
+pub fn visual(day: u32) -> Option<Visual> {
+    match day {
+        6 => Some(day06::VISUAL),
+        16 => Some(day16::VISUAL),
+        20 => Some(day20::VISUAL),
+        _ => None,
+    }
+}
+
+/// Renames of the labels of the inputs of the day, if it has them
+pub fn anonymizer(day: u32) -> Option<Anonymizer> {
+    match day {
+        5 => Some(day05::ANONYMIZER),
+        23 => Some(day23::ANONYMIZER),
+        24 => Some(day24::ANONYMIZER),
+        _ => None,
+    }
+}
+
+#[test]
+fn test_solutions() {
+    let solutions = solutions();
+    let main = solutions.iter().filter(|s| s.is_main()).collect::<Vec<_>>();
+    assert_eq!(main.len(), 49);
+    assert!(main
+        .windows(2)
+        .all(|w| (w[0].day, w[0].part) < (w[1].day, w[1].part)));
+    assert!(solutions
+        .windows(2)
+        .all(|w| (w[0].day, w[0].part) <= (w[1].day, w[1].part)));
+}
+
+#[test]
+fn test_dumpers() -> aoc::Result<()> {
+    assert!((1..=25).all(|day| dumper(day).map(|d| d.day) == Some(day)));
+    let solutions = solutions();
+    for (day, parts, example) in [
+        (1, "a", day01::EXAMPLE),
+        (2, "a", day02::EXAMPLE),
+        (3, "ab", day03::EXAMPLE2),
+        (4, "a", day04::EXAMPLE0),
+        (5, "a", day05::EXAMPLE),
+        (6, "a", day06::EXAMPLE),
+        (7, "a", day07::EXAMPLE),
+        (8, "a", day08::EXAMPLE),
+        (9, "a", day09::EXAMPLE),
+        (10, "a", day10::EXAMPLE),
+        (11, "a", day11::EXAMPLE),
+        (12, "a", day12::EXAMPLE1),
+        (13, "a", day13::EXAMPLE),
+        (14, "a", day14::EXAMPLE),
+        (15, "ab", day15::EXAMPLE2),
+        (16, "a", day16::EXAMPLE1),
+        (17, "a", day17::EXAMPLE),
+        (18, "a", day18::EXAMPLE),
+        (19, "a", day19::EXAMPLE),
+        (20, "a", day20::EXAMPLE),
+        (21, "ab", day21::EXAMPLE),
+        (22, "a", day22::EXAMPLE1),
+        (23, "ab", day23::EXAMPLE),
+        (24, "a", day24::EXAMPLE2),
+        (25, "a", day25::EXAMPLE),
+    ] {
+        let dumper = dumper(day).unwrap();
+        let json = (dumper.dump)(&mut example.as_bytes())?;
+        let input = (dumper.load)(&json)?;
+        assert_eq!((dumper.dump)(&mut input.as_bytes())?, json);
+        for solution in solutions
+            .iter()
+            .filter(|s| s.day == day && parts.contains(s.part))
+        {
+            let run =
+                |input: &str| (solution.run)(&mut input.as_bytes()).map_err(|e| e.to_string());
+            assert_eq!(run(&input), run(example), "{}", solution.name());
+        }
+    }
+    Ok(())
+}
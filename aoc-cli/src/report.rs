@@ -0,0 +1,309 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! `aoc report`: status and runtime of every solution, in Markdown
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc::{Result, Solution};
+
+use crate::answers::Answers;
+use crate::cache::Cache;
+use crate::inputs;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The answer matches the one in the database
+    Verified,
+    Wrong {
+        expected: String,
+    },
+    /// There's no answer in the database to compare with
+    Unverified,
+    NoInput,
+    Failed(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Verified => write!(f, "verified"),
+            Status::Wrong { expected } => write!(f, "WRONG, expected {}", expected),
+            Status::Unverified => write!(f, "unverified"),
+            Status::NoInput => write!(f, "no input"),
+            Status::Failed(e) => write!(f, "FAILED: {}", e),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u32,
+    pub part: char,
+    pub status: Status,
+    pub median: Option<Duration>,
+    /// The answer and median came from the cache
+    pub cached: bool,
+}
+
+/// Run the solution `runs` times, returning its answer and median time
+pub fn time(solution: &Solution, input: &str, runs: usize) -> Result<(String, Duration)> {
+    let mut times = vec![];
+    let mut answer = String::new();
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        answer = (solution.run)(&mut input.as_bytes())?;
+        times.push(start.elapsed());
+    }
+    times.sort();
+    Ok((answer, times[times.len() / 2]))
+}
+
+/// Run the solution `runs` times and check its answer; with a cache,
+/// only if it doesn't have the answer
+pub fn run(
+    solution: &Solution,
+    input: Option<&str>,
+    answers: &Answers,
+    runs: usize,
+    cache: Option<&mut Cache>,
+) -> Entry {
+    let mut entry = Entry {
+        day: solution.day,
+        part: solution.part,
+        status: Status::NoInput,
+        median: None,
+        cached: false,
+    };
+    let Some(input) = input else {
+        return entry;
+    };
+    let cached = cache
+        .as_deref()
+        .and_then(|cache| cache.get(solution.day, solution.part, input));
+    entry.cached = cached.is_some();
+    let result = match cached {
+        Some(cached) => Ok((cached.answer.clone(), cached.time)),
+        None => time(solution, input, runs).inspect(|(answer, median)| {
+            if let Some(cache) = cache {
+                cache.insert(solution.day, solution.part, input, answer, *median);
+            }
+        }),
+    };
+    let answer = match result {
+        Ok((answer, median)) => {
+            entry.median = Some(median);
+            answer
+        }
+        Err(e) => {
+            entry.status = Status::Failed(e.to_string());
+            return entry;
+        }
+    };
+    entry.status = check(answers, solution.day, solution.part, &answer);
+    entry
+}
+
+/// Status of an answer given the database
+pub fn check(answers: &Answers, day: u32, part: char, answer: &str) -> Status {
+    match answers.get(day, part) {
+        None => Status::Unverified,
+        Some(expected) if expected == answer => Status::Verified,
+        Some(expected) => Status::Wrong {
+            expected: expected.to_string(),
+        },
+    }
+}
+
+/// Run the main implementation of every part
+pub fn run_all(
+    solutions: &[Solution],
+    root: &Path,
+    answers: &Answers,
+    runs: usize,
+    mut cache: Option<&mut Cache>,
+) -> Result<Vec<Entry>> {
+    let mut entries = vec![];
+    let mut current: Option<(u32, Option<String>)> = None;
+    for solution in solutions.iter().filter(|s| s.is_main()) {
+        if current.as_ref().map(|c| c.0) != Some(solution.day) {
+            current = Some((solution.day, inputs::read(root, solution.day)?));
+        }
+        let input = current.as_ref().and_then(|c| c.1.as_deref());
+        entries.push(run(solution, input, answers, runs, cache.as_deref_mut()));
+    }
+    Ok(entries)
+}
+
+/// Short human-readable duration, e.g. `12.3ms`
+pub fn format_duration(d: Duration) -> String {
+    let secs = d.as_secs_f64();
+    if secs >= 1.0 {
+        format!("{:.2}s", secs)
+    } else if secs >= 1e-3 {
+        format!("{:.1}ms", secs * 1e3)
+    } else {
+        format!("{}µs", d.as_micros())
+    }
+}
+
+/// Markdown table with one line per solution
+pub fn table(entries: &[Entry]) -> String {
+    let mut out = String::new();
+    writeln!(out, "| Day | Part | Status | Median |").unwrap();
+    writeln!(out, "|----:|:----:|:-------|-------:|").unwrap();
+    for e in entries {
+        let mut median = e.median.map_or("-".to_string(), format_duration);
+        if e.cached {
+            median.push_str(" (cached)");
+        }
+        writeln!(
+            out,
+            "| {:02} | {} | {} | {} |",
+            e.day,
+            e.part,
+            e.status.to_string().replace('|', "\\|"),
+            median
+        )
+        .unwrap();
+    }
+    let verified = entries
+        .iter()
+        .filter(|e| e.status == Status::Verified)
+        .count();
+    writeln!(out).unwrap();
+    writeln!(out, "{} of {} parts verified.", verified, entries.len()).unwrap();
+    out
+}
+
+/// Bar chart of the `top` slowest days, adding the medians of the parts
+pub fn histogram(entries: &[Entry], top: usize, width: usize) -> String {
+    let mut days = BTreeMap::<u32, Duration>::default();
+    for e in entries {
+        if let Some(median) = e.median {
+            *days.entry(e.day).or_default() += median;
+        }
+    }
+    let mut days = days.into_iter().collect::<Vec<_>>();
+    days.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    days.truncate(top);
+    let Some(&(_, slowest)) = days.first() else {
+        return String::new();
+    };
+    let mut out = String::new();
+    writeln!(out, "```text").unwrap();
+    for (day, time) in days {
+        let len = if slowest.is_zero() {
+            0
+        } else {
+            (time.as_secs_f64() / slowest.as_secs_f64() * width as f64).round() as usize
+        };
+        writeln!(
+            out,
+            "day{:02} {:<width$} {}",
+            day,
+            "█".repeat(len.max(1)),
+            format_duration(time),
+            width = width
+        )
+        .unwrap();
+    }
+    writeln!(out, "```").unwrap();
+    out
+}
+
+#[cfg(test)]
+fn entry(day: u32, part: char, status: Status, ms: Option<u64>) -> Entry {
+    Entry {
+        day,
+        part,
+        status,
+        median: ms.map(Duration::from_millis),
+        cached: false,
+    }
+}
+
+#[test]
+fn test_run() {
+    let solution = Solution {
+        day: 1,
+        part: 'a',
+        strategy: "",
+        run: |bufin| Ok(std::io::read_to_string(bufin)?.trim().len().to_string()),
+    };
+    let answers = Answers::parse("day01a 3\n").unwrap();
+    let e = run(&solution, Some("abc\n"), &answers, 3, None);
+    assert_eq!(e.status, Status::Verified);
+    assert!(e.median.is_some());
+    let e = run(&solution, Some("ab\n"), &answers, 3, None);
+    assert_eq!(
+        e.status,
+        Status::Wrong {
+            expected: "3".to_string()
+        }
+    );
+    let e = run(&solution, None, &answers, 3, None);
+    assert_eq!(e.status, Status::NoInput);
+    assert_eq!(e.median, None);
+    let e = run(&solution, Some(""), &Answers::default(), 1, None);
+    assert_eq!(e.status, Status::Unverified);
+}
+
+#[test]
+fn test_run_cached() {
+    let path = std::env::temp_dir().join(format!("aoc-report-cache-{}.txt", std::process::id()));
+    let mut cache = Cache::load(&path);
+    let solution = Solution {
+        day: 1,
+        part: 'a',
+        strategy: "",
+        run: |bufin| Ok(std::io::read_to_string(bufin)?.len().to_string()),
+    };
+    let answers = Answers::default();
+    let e = run(&solution, Some("abc"), &answers, 1, Some(&mut cache));
+    assert!(!e.cached);
+    let cached = run(&solution, Some("abc"), &answers, 1, Some(&mut cache));
+    assert!(cached.cached);
+    assert_eq!(cached.median, e.median);
+    assert!(table(&[cached]).contains(" (cached) |"));
+    assert!(!run(&solution, Some("abc"), &answers, 1, None).cached);
+}
+
+#[test]
+fn test_table() {
+    let entries = vec![
+        entry(1, 'a', Status::Verified, Some(2)),
+        entry(1, 'b', Status::Failed("a|b".to_string()), None),
+    ];
+    assert_eq!(
+        table(&entries),
+        "| Day | Part | Status | Median |\n\
+         |----:|:----:|:-------|-------:|\n\
+         | 01 | a | verified | 2.0ms |\n\
+         | 01 | b | FAILED: a\\|b | - |\n\
+         \n\
+         1 of 2 parts verified.\n"
+    );
+}
+
+#[test]
+fn test_histogram() {
+    let entries = vec![
+        entry(1, 'a', Status::Unverified, Some(10)),
+        entry(1, 'b', Status::Unverified, Some(30)),
+        entry(2, 'a', Status::Unverified, Some(20)),
+        entry(3, 'a', Status::NoInput, None),
+        entry(4, 'a', Status::Unverified, Some(1)),
+    ];
+    assert_eq!(
+        histogram(&entries, 2, 4),
+        "```text\nday01 ████ 40.0ms\nday02 ██   20.0ms\n```\n"
+    );
+    assert_eq!(histogram(&[], 2, 4), "");
+    assert_eq!(format_duration(Duration::from_micros(15)), "15µs");
+    assert_eq!(format_duration(Duration::from_millis(1500)), "1.50s");
+}
@@ -0,0 +1,191 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! `aoc run`: answers of both parts of a day, with the time of each phase
+//!
+//! Days with a [`Both`] parse the input once and share work between the
+//! parts; the others run the main solution of each part, so that their
+//! `a` and `b` phases include parsing.
+//!
+//! With a [`Cache`], a day whose parts all have cached answers doesn't
+//! run at all: the phases are the cached times of the parts.
+
+use std::fmt::Write;
+use std::time::Duration;
+
+use aoc::{Both, Phases, Result, Solution};
+
+use crate::cache::Cache;
+use crate::report::format_duration;
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub day: u32,
+    pub answers: Vec<(char, String)>,
+    pub phases: Phases,
+    /// The answers came from the cache
+    pub cached: bool,
+}
+
+pub fn run(day: u32, both: Option<Both>, solutions: &[Solution], input: &str) -> Result<Run> {
+    let mut phases = Phases::default();
+    let answers = if let Some(both) = both {
+        let (a, b) = (both.run)(&mut input.as_bytes(), &mut phases)?;
+        vec![('a', a), ('b', b)]
+    } else {
+        let mut answers = vec![];
+        for solution in solutions.iter().filter(|s| s.day == day && s.is_main()) {
+            answers.push((solution.part, (solution.run)(&mut input.as_bytes())?));
+            phases.lap(if solution.part == 'a' { "a" } else { "b" });
+        }
+        answers
+    };
+    Ok(Run {
+        day,
+        answers,
+        phases,
+        cached: false,
+    })
+}
+
+/// [`run`] the day unless the cache has the answers of all its parts
+pub fn run_cached(
+    day: u32,
+    both: Option<Both>,
+    solutions: &[Solution],
+    input: &str,
+    cache: Option<&mut Cache>,
+) -> Result<Run> {
+    let Some(cache) = cache else {
+        return run(day, both, solutions, input);
+    };
+    let parts = if both.is_some() {
+        vec!['a', 'b']
+    } else {
+        solutions
+            .iter()
+            .filter(|s| s.day == day && s.is_main())
+            .map(|s| s.part)
+            .collect()
+    };
+    let cached = parts
+        .iter()
+        .map(|&part| cache.get(day, part, input).map(|c| (part, c)))
+        .collect::<Option<Vec<_>>>();
+    if let Some(cached) = cached.filter(|c| !c.is_empty()) {
+        let mut phases = Phases::default();
+        phases.laps = cached
+            .iter()
+            .map(|(part, c)| (if *part == 'a' { "a" } else { "b" }, c.time))
+            .collect();
+        return Ok(Run {
+            day,
+            answers: cached
+                .into_iter()
+                .map(|(part, c)| (part, c.answer.clone()))
+                .collect(),
+            phases,
+            cached: true,
+        });
+    }
+    let run = run(day, both, solutions, input)?;
+    // Phases that aren't parts, like parsing, count for the next part
+    let mut time = Duration::ZERO;
+    for (name, lap) in &run.phases.laps {
+        time += *lap;
+        if let Some((part, answer)) = run.answers.iter().find(|(p, _)| p.to_string() == *name) {
+            cache.insert(day, *part, input, answer, time);
+            time = Duration::ZERO;
+        }
+    }
+    Ok(run)
+}
+
+impl Run {
+    /// One line per answer, and the timings in the last one
+    pub fn format(&self) -> String {
+        let mut out = String::new();
+        for (part, answer) in &self.answers {
+            write!(out, "day{:02}{}: {}", self.day, part, answer).unwrap();
+            writeln!(out, "{}", if self.cached { " (cached)" } else { "" }).unwrap();
+        }
+        for (name, time) in &self.phases.laps {
+            write!(out, "{} {}, ", name, format_duration(*time)).unwrap();
+        }
+        writeln!(out, "total {}", format_duration(self.phases.total())).unwrap();
+        out
+    }
+}
+
+#[cfg(test)]
+fn solution(part: char, run: fn(&mut dyn std::io::BufRead) -> Result<String>) -> Solution {
+    Solution {
+        day: 1,
+        part,
+        strategy: "",
+        run,
+    }
+}
+
+#[test]
+fn test_run_parts() -> Result<()> {
+    let solutions = [
+        solution('a', |bufin| {
+            Ok(std::io::read_to_string(bufin)?.len().to_string())
+        }),
+        solution('b', |_| Ok("b".to_string())),
+    ];
+    let run = run(1, None, &solutions, "abc")?;
+    assert_eq!(
+        run.answers,
+        vec![('a', "3".to_string()), ('b', "b".to_string())]
+    );
+    let out = run.format();
+    assert!(out.starts_with("day01a: 3\nday01b: b\na "));
+    assert!(out.contains(", b ") && out.contains(", total "));
+    Ok(())
+}
+
+#[test]
+fn test_run_both() -> Result<()> {
+    let both = Both {
+        day: 1,
+        run: |_, phases| {
+            phases.lap("parse");
+            phases.lap("a");
+            phases.lap("b");
+            Ok(("1".to_string(), "2".to_string()))
+        },
+    };
+    let run = run(1, Some(both), &[], "")?;
+    assert_eq!(run.answers[1], ('b', "2".to_string()));
+    assert!(run.format().contains("\nparse "));
+    Ok(())
+}
+
+#[test]
+fn test_run_cached() -> Result<()> {
+    let path = std::env::temp_dir().join(format!("aoc-run-cache-{}.txt", std::process::id()));
+    let mut cache = Cache::load(&path);
+    let both = Both {
+        day: 1,
+        run: |_, phases| {
+            phases.lap("parse");
+            phases.lap("a");
+            phases.lap("b");
+            Ok(("1".to_string(), "2".to_string()))
+        },
+    };
+    let first = run_cached(1, Some(both), &[], "x", Some(&mut cache))?;
+    assert!(!first.cached);
+    let cached = cache.get(1, 'a', "x").unwrap();
+    assert_eq!(cached.time, first.phases.laps[0].1 + first.phases.laps[1].1);
+    let second = run_cached(1, Some(both), &[], "x", Some(&mut cache))?;
+    assert!(second.cached);
+    assert_eq!(second.answers, first.answers);
+    assert_eq!(second.phases.total(), first.phases.total());
+    assert!(second.format().starts_with("day01a: 1 (cached)\n"));
+    assert!(!run_cached(1, Some(both), &[], "y", Some(&mut cache))?.cached);
+    Ok(())
+}
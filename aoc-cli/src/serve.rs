@@ -0,0 +1,293 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! `aoc serve`: the solvers over HTTP, for other local tools
+//!
+//! `POST /day/<day>/<part>` with the input as the body solves the part
+//! with its main strategy, or with the one in `?strategy=<name>`, and
+//! answers with a JSON [`Reply`].
+//!
+//! Each solve runs in its own thread, at most `concurrency` at a time;
+//! requests beyond that get a 503 right away. A solve that takes longer
+//! than `timeout` gets a 504, but its thread can't be stopped, so it
+//! keeps its slot until it finishes.
+//!
+//! Bodies over [`http::MAX_BODY`] get a 413, and clients that stall for
+//! [`http::READ_TIMEOUT`] while sending are dropped.
+
+use std::net::{SocketAddr, TcpListener};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use aoc::{PuzzleError, Result, Solution};
+
+use crate::http::{self, Request, Response};
+
+/// Solvers can recurse deeply, more so in debug builds
+const STACK_SIZE: usize = 256 << 20;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Config {
+    /// Maximum number of solves running at the same time
+    pub concurrency: usize,
+    pub timeout: Duration,
+}
+
+/// Body of every response
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Reply {
+    pub day: u32,
+    pub part: char,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    /// Time spent solving, input parsing included
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub elapsed_us: Option<u128>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Exit code of the error, as in `aoc::exit_code`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<i32>,
+}
+
+impl Reply {
+    fn response(&self, status: u16) -> Response {
+        let body = serde_json::to_string(self).unwrap_or_default();
+        Response::new(status, "application/json", body)
+    }
+
+    fn error(mut self, status: u16, error: impl ToString) -> Response {
+        self.error = Some(error.to_string());
+        self.response(status)
+    }
+}
+
+struct State {
+    solutions: Vec<Solution>,
+    config: Config,
+    running: AtomicUsize,
+}
+
+/// Releases the slot of a solve when dropped
+struct Slot(Arc<State>);
+
+impl Slot {
+    fn take(state: &Arc<State>) -> Option<Slot> {
+        state
+            .running
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |running| {
+                (running < state.config.concurrency).then_some(running + 1)
+            })
+            .ok()
+            .map(|_| Slot(state.clone()))
+    }
+}
+
+impl Drop for Slot {
+    fn drop(&mut self) {
+        self.0.running.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Day, part and strategy of a `/day/<day>/<part>[?strategy=<name>]`
+fn parse_path(path: &str) -> Option<(u32, char, &str)> {
+    let (path, query) = path.split_once('?').unwrap_or((path, ""));
+    let (day, part) = path.strip_prefix("/day/")?.split_once('/')?;
+    let mut part = part.chars();
+    let (Some(p), None) = (part.next(), part.next()) else {
+        return None;
+    };
+    let strategy = query
+        .split('&')
+        .find_map(|q| q.strip_prefix("strategy="))
+        .unwrap_or("");
+    Some((day.parse().ok()?, p, strategy))
+}
+
+fn handle(state: &Arc<State>, request: &Request) -> Response {
+    let Some((day, part, strategy)) = parse_path(&request.path) else {
+        return Response::text(404, "404 Not Found");
+    };
+    let reply = Reply {
+        day,
+        part,
+        ..Reply::default()
+    };
+    if request.method != "POST" {
+        return reply.error(405, "use POST with the input as the body");
+    }
+    let Some(solution) = state
+        .solutions
+        .iter()
+        .find(|s| s.day == day && s.part == part && s.strategy == strategy)
+        .copied()
+    else {
+        return reply.error(404, "no such solution");
+    };
+    let Ok(input) = String::from_utf8(request.body.clone()) else {
+        return reply.error(400, "the input is not UTF-8");
+    };
+    let Some(slot) = Slot::take(state) else {
+        return reply.error(503, "too many solves running, try again later");
+    };
+    let (sender, receiver) = mpsc::channel();
+    let spawned = thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let _slot = slot;
+            let start = Instant::now();
+            let result =
+                panic::catch_unwind(AssertUnwindSafe(|| (solution.run)(&mut input.as_bytes())));
+            let _ = sender.send((result, start.elapsed()));
+        });
+    if let Err(e) = spawned {
+        return reply.error(500, e);
+    }
+    let reply = |elapsed: Duration| Reply {
+        elapsed_us: Some(elapsed.as_micros()),
+        ..reply.clone()
+    };
+    match receiver.recv_timeout(state.config.timeout) {
+        Ok((Ok(Ok(answer)), elapsed)) => Reply {
+            answer: Some(answer),
+            ..reply(elapsed)
+        }
+        .response(200),
+        Ok((Ok(Err(report)), elapsed)) => {
+            let status = match report.downcast_ref::<PuzzleError>() {
+                Some(PuzzleError::InvalidInput { .. }) => 400,
+                Some(_) => 422,
+                None => 500,
+            };
+            Reply {
+                exit_code: Some(aoc::exit_code(&report)),
+                ..reply(elapsed)
+            }
+            .error(status, report)
+        }
+        Ok((Err(_), elapsed)) => reply(elapsed).error(500, "the solver panicked"),
+        Err(_) => reply(state.config.timeout).error(504, "timed out"),
+    }
+}
+
+#[derive(Debug)]
+pub struct Server {
+    pub addr: SocketAddr,
+}
+
+impl Server {
+    /// Listen on `127.0.0.1:port` and serve each connection from its own
+    /// thread; port 0 picks a free one
+    pub fn start(solutions: Vec<Solution>, config: Config, port: u16) -> Result<Server> {
+        let listener = TcpListener::bind(("127.0.0.1", port))?;
+        let addr = listener.local_addr()?;
+        let state = Arc::new(State {
+            solutions,
+            config,
+            running: AtomicUsize::new(0),
+        });
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                let state = state.clone();
+                thread::spawn(move || {
+                    if let Err(e) = http::serve_connection(stream, |r| handle(&state, r)) {
+                        eprintln!("serve: {}", e);
+                    }
+                });
+            }
+        });
+        Ok(Server { addr })
+    }
+
+    pub fn url(&self) -> String {
+        format!("http://{}", self.addr)
+    }
+}
+
+#[test]
+fn test_parse_path() {
+    assert_eq!(parse_path("/day/6/a"), Some((6, 'a', "")));
+    assert_eq!(
+        parse_path("/day/13/a?strategy=linalg"),
+        Some((13, 'a', "linalg"))
+    );
+    assert_eq!(parse_path("/day/6/ab"), None);
+    assert_eq!(parse_path("/2024/day/6/a"), None);
+}
+
+#[cfg(test)]
+fn post(url: &str, input: &str) -> (u16, serde_json::Value) {
+    let response = match ureq::post(url).send_string(input) {
+        Ok(response) => response,
+        Err(ureq::Error::Status(_, response)) => response,
+        Err(e) => panic!("{}", e),
+    };
+    (response.status(), response.into_json().unwrap())
+}
+
+#[cfg(test)]
+fn server(concurrency: usize, timeout: Duration) -> Result<Server> {
+    let solution = |part, run| Solution {
+        day: 1,
+        part,
+        strategy: "",
+        run,
+    };
+    let solutions = vec![
+        solution('a', |bufin| {
+            let input = std::io::read_to_string(bufin)?;
+            match input.trim().parse::<u32>() {
+                Ok(n) => Ok((2 * n).to_string()),
+                Err(e) => Err(PuzzleError::invalid_input(e).into()),
+            }
+        }),
+        solution('b', |_| {
+            thread::sleep(Duration::from_millis(500));
+            Ok("late".to_string())
+        }),
+    ];
+    Server::start(
+        solutions,
+        Config {
+            concurrency,
+            timeout,
+        },
+        0,
+    )
+}
+
+#[test]
+fn test_serve() -> Result<()> {
+    let server = server(4, Duration::from_secs(5))?;
+    let (status, reply) = post(&format!("{}/day/1/a", server.url()), "21\n");
+    assert_eq!(status, 200);
+    assert_eq!(reply["answer"], "42");
+    assert_eq!(reply["part"], "a");
+    assert!(reply["elapsed_us"].is_u64());
+    let (status, reply) = post(&format!("{}/day/1/a", server.url()), "x\n");
+    assert_eq!(status, 400);
+    assert_eq!(reply["exit_code"], 2);
+    assert!(reply["error"].as_str().unwrap().contains("invalid input"));
+    let (status, _) = post(&format!("{}/day/2/a", server.url()), "");
+    assert_eq!(status, 404);
+    Ok(())
+}
+
+#[test]
+fn test_serve_limits() -> Result<()> {
+    let server = server(1, Duration::from_millis(100))?;
+    let url = format!("{}/day/1/b", server.url());
+    let (status, reply) = post(&url, "");
+    assert_eq!(status, 504);
+    assert_eq!(reply["error"], "timed out");
+    // The timed-out solve is still running and holds the only slot
+    let (status, _) = post(&format!("{}/day/1/a", server.url()), "1\n");
+    assert_eq!(status, 503);
+    Ok(())
+}
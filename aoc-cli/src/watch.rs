@@ -0,0 +1,268 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! `aoc watch`: re-run a day whenever its code or its input change
+//!
+//! The day's crate and the input are watched with inotify. On each change
+//! we build the day's binaries, run its tests and then run the binaries
+//! on the input, printing each answer and time next to the ones of the
+//! previous run. The binaries are built and run with cargo, as `aoc`
+//! itself can't pick up the new code.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::ffi::{OsStr, OsString};
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::thread;
+use std::time::Duration;
+
+use inotify::{Inotify, WatchDescriptor, WatchMask};
+
+use aoc::{eyre, Result};
+
+/// What a day binary printed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub answer: String,
+    pub elapsed: String,
+}
+
+/// The answer and the time in the output of `aoc::do_main`
+pub fn parse_output(stdout: &str) -> Option<Answer> {
+    let (answer, elapsed) = stdout.trim_end().rsplit_once("Elapsed: ")?;
+    Some(Answer {
+        answer: answer.trim_end().to_string(),
+        elapsed: elapsed.to_string(),
+    })
+}
+
+/// Line with the answer of a part, compared with the previous run
+pub fn compare(name: &str, last: Option<&Answer>, new: &Answer) -> String {
+    match last {
+        None => format!("{}: {} in {}", name, new.answer, new.elapsed),
+        Some(last) if last.answer == new.answer => format!(
+            "{}: {} (same) in {}, was {}",
+            name, new.answer, new.elapsed, last.elapsed
+        ),
+        Some(last) => format!(
+            "{}: {} -> {} in {}, was {}",
+            name, last.answer, new.answer, new.elapsed, last.elapsed
+        ),
+    }
+}
+
+/// Directories to watch, each with the names of the files in it that
+/// matter, or none if all of them do
+pub fn watch_list(root: &Path, day: u32, input: &Path) -> BTreeMap<PathBuf, Vec<OsString>> {
+    let dir = root.join(format!("day{:02}", day));
+    let mut list = BTreeMap::<PathBuf, Vec<OsString>>::new();
+    for sub in ["src", "src/bin", "examples"] {
+        list.insert(dir.join(sub), vec![]);
+    }
+    list.insert(dir, vec!["Cargo.toml".into(), "build.rs".into()]);
+    let parent = match input.parent() {
+        Some(parent) if parent != Path::new("") => parent.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    if let Some(name) = input.file_name() {
+        match list.get_mut(&parent) {
+            Some(only) if only.is_empty() => {}
+            Some(only) => only.push(name.to_os_string()),
+            None => {
+                list.insert(parent, vec![name.to_os_string()]);
+            }
+        }
+    }
+    list
+}
+
+/// Whether a change to file `name` matters, given the `only` of its
+/// directory; hidden and backup files never do
+fn relevant(name: &OsStr, only: &[OsString]) -> bool {
+    if only.is_empty() {
+        let name = name.to_string_lossy();
+        !name.starts_with('.') && !name.ends_with('~')
+    } else {
+        only.iter().any(|o| o == name)
+    }
+}
+
+/// Parts of the day, from its binaries
+fn parts(root: &Path, day: u32) -> Result<Vec<char>> {
+    let dir = root.join(format!("day{:02}/src/bin", day));
+    let prefix = format!("day{:02}", day);
+    let mut parts = vec![];
+    for entry in fs::read_dir(&dir).map_err(|e| eyre!("reading {}: {}", dir.display(), e))? {
+        let name = entry?.file_name().to_string_lossy().into_owned();
+        if let Some(part) = name
+            .strip_prefix(&prefix)
+            .and_then(|rest| rest.strip_suffix(".rs"))
+            .filter(|part| part.len() == 1)
+        {
+            parts.extend(part.chars());
+        }
+    }
+    parts.sort();
+    Ok(parts)
+}
+
+fn cargo(root: &Path) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(root);
+    command
+}
+
+/// The last `n` lines of the output, to show why a step failed
+fn tail(output: &Output, n: usize) -> String {
+    let text = [&output.stdout[..], &output.stderr[..]]
+        .map(String::from_utf8_lossy)
+        .concat();
+    let lines = text.lines().collect::<Vec<_>>();
+    lines[lines.len().saturating_sub(n)..].join("\n")
+}
+
+struct Watcher {
+    root: PathBuf,
+    day: u32,
+    input: PathBuf,
+    last: BTreeMap<char, Answer>,
+}
+
+impl Watcher {
+    fn run(&mut self, why: &str) -> Result<()> {
+        let package = format!("day{:02}", self.day);
+        println!("==> {}", why);
+        let build = cargo(&self.root)
+            .args(["build", "--release", "--quiet", "--bins", "-p", &package])
+            .output()?;
+        if !build.status.success() {
+            println!("build failed:\n{}", tail(&build, 30));
+            return Ok(());
+        }
+        let test = cargo(&self.root)
+            .args(["test", "--release", "--quiet", "-p", &package])
+            .output()?;
+        if test.status.success() {
+            println!("tests ok");
+        } else {
+            println!("tests failed:\n{}", tail(&test, 20));
+        }
+        if !self.input.exists() {
+            println!("no input at {}", self.input.display());
+            return Ok(());
+        }
+        for part in parts(&self.root, self.day)? {
+            let name = format!("{}{}", package, part);
+            let output = cargo(&self.root)
+                .args([
+                    "run",
+                    "--release",
+                    "--quiet",
+                    "-p",
+                    &package,
+                    "--bin",
+                    &name,
+                ])
+                .stdin(File::open(&self.input)?)
+                .output()?;
+            match parse_output(&String::from_utf8_lossy(&output.stdout)) {
+                Some(answer) if output.status.success() => {
+                    println!("{}", compare(&name, self.last.get(&part), &answer));
+                    self.last.insert(part, answer);
+                }
+                _ => println!("{} failed:\n{}", name, tail(&output, 10)),
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn watch(root: &Path, day: u32, input: &Path) -> Result<()> {
+    let mut inotify = Inotify::init()?;
+    let mask = WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO | WatchMask::DELETE;
+    let mut watched = Vec::<(WatchDescriptor, Vec<OsString>)>::new();
+    for (dir, only) in watch_list(root, day, input) {
+        if dir.is_dir() {
+            watched.push((inotify.watches().add(&dir, mask)?, only));
+        }
+    }
+    let mut watcher = Watcher {
+        root: root.to_path_buf(),
+        day,
+        input: input.to_path_buf(),
+        last: BTreeMap::new(),
+    };
+    watcher.run("start")?;
+    let mut buffer = [0; 4096];
+    loop {
+        let changed = inotify
+            .read_events_blocking(&mut buffer)?
+            .filter_map(|event| {
+                let name = event.name?;
+                let (_, only) = watched.iter().find(|(wd, _)| *wd == event.wd)?;
+                relevant(name, only).then(|| name.to_string_lossy().into_owned())
+            })
+            .next();
+        let Some(changed) = changed else {
+            continue;
+        };
+        // Let the editor finish writing, and drop the rest of the burst
+        thread::sleep(Duration::from_millis(200));
+        loop {
+            match inotify.read_events(&mut buffer) {
+                Ok(_) => {}
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        watcher.run(&format!("{} changed", changed))?;
+    }
+}
+
+#[test]
+fn test_parse_output() {
+    assert_eq!(
+        parse_output("7036\nElapsed: 3ms 241us\n"),
+        Some(Answer {
+            answer: "7036".to_string(),
+            elapsed: "3ms 241us".to_string(),
+        })
+    );
+    assert_eq!(parse_output("7036\n"), None);
+}
+
+#[test]
+fn test_compare() {
+    let answer = |answer: &str, elapsed: &str| Answer {
+        answer: answer.to_string(),
+        elapsed: elapsed.to_string(),
+    };
+    let new = answer("46", "2ms");
+    assert_eq!(compare("day16b", None, &new), "day16b: 46 in 2ms");
+    assert_eq!(
+        compare("day16b", Some(&answer("46", "3ms")), &new),
+        "day16b: 46 (same) in 2ms, was 3ms"
+    );
+    assert_eq!(
+        compare("day16b", Some(&answer("45", "3ms")), &new),
+        "day16b: 45 -> 46 in 2ms, was 3ms"
+    );
+}
+
+#[test]
+fn test_watch_list() {
+    let list = watch_list(Path::new("r"), 6, Path::new("r/day06/input.txt"));
+    assert_eq!(list[Path::new("r/day06/src")], Vec::<OsString>::new());
+    let only = &list[Path::new("r/day06")];
+    assert!(relevant(OsStr::new("input.txt"), only));
+    assert!(relevant(OsStr::new("Cargo.toml"), only));
+    assert!(!relevant(OsStr::new("notes.txt"), only));
+    assert!(relevant(OsStr::new("a.rs"), &[]));
+    assert!(!relevant(OsStr::new(".a.rs.swp"), &[]));
+    let list = watch_list(Path::new("r"), 6, Path::new("/tmp/big.txt"));
+    assert_eq!(list[Path::new("/tmp")], vec![OsString::from("big.txt")]);
+}
@@ -0,0 +1,227 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Shareable equivalents of puzzle inputs
+//!
+//! Our inputs can't be redistributed, but an input with its labels
+//! renamed - node names, wire names, page numbers - has the same
+//! structure and is no longer the one we got. The days that have an
+//! [`Anonymizer`], declared with [`anonymizer!`](crate::anonymizer),
+//! pick the renames, and say what the answers of the anonymized input
+//! should be given the ones of the original, which [`Anonymizer::check`]
+//! verifies with the day's solvers.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::error::PuzzleError;
+use crate::generator::Rng;
+use crate::{Result, Solution};
+
+/// Renames of the labels of an input, from the original to the new one
+pub type Labels = BTreeMap<String, String>;
+
+/// Replace the labels of `input`, which are the maximal runs of ASCII
+/// alphanumerics, that have a rename
+pub fn relabel(input: &str, labels: &Labels) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+    while !rest.is_empty() {
+        let alnum = rest.starts_with(|c: char| c.is_ascii_alphanumeric());
+        let len = rest
+            .find(|c: char| c.is_ascii_alphanumeric() != alnum)
+            .unwrap_or(rest.len());
+        let (token, tail) = rest.split_at(len);
+        out.push_str(labels.get(token).map_or(token, String::as_str));
+        rest = tail;
+    }
+    out
+}
+
+/// Rename each of `names` to a distinct random one of `candidates`,
+/// which can include the names themselves; an input with more names
+/// than candidates is unsupported
+pub fn rename<'a>(
+    rng: &mut Rng,
+    names: impl IntoIterator<Item = &'a str>,
+    candidates: impl IntoIterator<Item = String>,
+) -> Result<Labels> {
+    let mut candidates = candidates.into_iter().collect::<Vec<_>>();
+    rng.shuffle(&mut candidates);
+    let mut names = names.into_iter().collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+    if names.len() > candidates.len() {
+        return Err(PuzzleError::unsupported(format!(
+            "{} distinct labels but only {} replacements",
+            names.len(),
+            candidates.len()
+        ))
+        .into());
+    }
+    Ok(names
+        .into_iter()
+        .map(str::to_string)
+        .zip(candidates)
+        .collect())
+}
+
+/// Answer that is a list of labels, relabeled and sorted again
+pub fn relabel_list(answer: &str, separator: &str, labels: &Labels) -> String {
+    let mut items = answer
+        .split(separator)
+        .map(|item| relabel(item, labels))
+        .collect::<Vec<_>>();
+    items.sort();
+    items.join(separator)
+}
+
+/// How to anonymize the inputs of a day
+#[derive(Clone, Copy)]
+pub struct Anonymizer {
+    pub day: u32,
+    /// Renames of the labels of the input
+    pub labels: fn(input: &str, rng: &mut Rng) -> Result<Labels>,
+    /// Answer of the anonymized input, given the original input and its
+    /// answer to the part
+    pub expected: fn(part: char, input: &str, answer: &str, labels: &Labels) -> Result<String>,
+}
+
+impl fmt::Debug for Anonymizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Anonymizer")
+            .field("day", &self.day)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Answers of a part for the original and the anonymized inputs,
+/// displayed as e.g. `a: 143 -> 123`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub part: char,
+    pub original: String,
+    pub expected: String,
+    pub anonymized: String,
+}
+
+impl Check {
+    pub fn ok(&self) -> bool {
+        self.expected == self.anonymized
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.part, self.original, self.anonymized)?;
+        if !self.ok() {
+            write!(f, ", expected {}", self.expected)?;
+        }
+        Ok(())
+    }
+}
+
+impl Anonymizer {
+    /// The anonymized input and the renames
+    pub fn anonymize(&self, input: &str, seed: u64) -> Result<(String, Labels)> {
+        let labels = (self.labels)(input, &mut Rng::new(seed))?;
+        Ok((relabel(input, &labels), labels))
+    }
+
+    /// Solve both inputs with the main solutions of the day
+    pub fn check(
+        &self,
+        solutions: &[Solution],
+        input: &str,
+        anonymized: &str,
+        labels: &Labels,
+    ) -> Result<Vec<Check>> {
+        let mut checks = vec![];
+        for solution in solutions
+            .iter()
+            .filter(|s| s.day == self.day && s.is_main())
+        {
+            let original = (solution.run)(&mut input.as_bytes())?;
+            let expected = (self.expected)(solution.part, input, &original, labels)?;
+            checks.push(Check {
+                part: solution.part,
+                original,
+                expected,
+                anonymized: (solution.run)(&mut anonymized.as_bytes())?,
+            });
+        }
+        Ok(checks)
+    }
+
+    /// For the tests of the days: anonymize `example`, assert that it
+    /// changed and that `solutions` get the expected answers, and return
+    /// it
+    pub fn check_example(&self, solutions: &[Solution], example: &str) -> Result<String> {
+        let (anonymized, labels) = self.anonymize(example, 1)?;
+        assert_ne!(anonymized, example);
+        let checks = self.check(solutions, example, &anonymized, &labels)?;
+        assert!(!checks.is_empty());
+        assert!(checks.iter().all(|c| c.ok()), "{:?}", checks);
+        Ok(anonymized)
+    }
+}
+
+/// Declare the `ANONYMIZER` of a day
+///
+/// ```ignore
+/// anonymizer! {
+///     day: 23,
+///     labels: |input, rng| ...,
+///     expected: |part, input, answer, labels| ...,
+/// }
+/// ```
+#[macro_export]
+macro_rules! anonymizer {
+    (
+        day: $day:literal,
+        labels: |$input:ident, $rng:ident| $labels:expr,
+        expected: |$part:ident, $input2:ident, $answer:ident, $labels2:ident| $expected:expr $(,)?
+    ) => {
+        pub const ANONYMIZER: $crate::anonymize::Anonymizer = $crate::anonymize::Anonymizer {
+            day: $day,
+            labels: |$input, $rng| $labels,
+            expected: |$part, $input2, $answer, $labels2| $expected,
+        };
+    };
+}
+
+#[test]
+fn test_relabel() {
+    let labels = [("ab", "cd"), ("x1", "y2"), ("42", "17")]
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect::<Labels>();
+    assert_eq!(relabel("ab-x1\nabc,42|ab\n", &labels), "cd-y2\nabc,17|cd\n");
+    assert_eq!(relabel("", &labels), "");
+    assert_eq!(relabel_list("x1,ab,zz", ",", &labels), "cd,y2,zz");
+}
+
+#[cfg(test)]
+use std::collections::BTreeSet;
+
+#[test]
+fn test_rename() -> Result<()> {
+    let mut rng = Rng::new(3);
+    let names = ["b", "a", "b", "c"];
+    let labels = rename(&mut rng, names, ('a'..='z').map(String::from))?;
+    assert_eq!(labels.len(), 3);
+    assert_eq!(labels.values().collect::<BTreeSet<_>>().len(), 3);
+    // A permutation
+    let labels = rename(&mut rng, names, ["a", "b", "c"].map(String::from))?;
+    assert_eq!(
+        labels.values().collect::<BTreeSet<_>>(),
+        labels.keys().collect()
+    );
+    let report = rename(&mut rng, names, ["a", "b"].map(String::from)).unwrap_err();
+    assert!(matches!(
+        report.downcast_ref::<PuzzleError>(),
+        Some(PuzzleError::Unsupported(_))
+    ));
+    Ok(())
+}
@@ -0,0 +1,223 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Checkpoints of long-running searches
+//!
+//! A search creates a [`Checkpoint`] named after the part and keyed by
+//! what it searches, restores its state with [`Checkpoint::resume`], and
+//! calls [`Checkpoint::tick`] on every step. The version of the state is
+//! part of the key too, and gets bumped when the state changes, so that
+//! older checkpoints are not resumed. Ticks save the state as JSON
+//! every [`INTERVAL`], and on Ctrl-C they save it and return a
+//! [`PuzzleError::Interrupted`] that ends the program.
+//!
+//! Checkpoints are off until [`enable`] is called, which `aoc run` and
+//! the binaries of the searching parts do; with them off, ticks do
+//! nothing and there is nothing to resume. Ctrl-C still ends the program
+//! right away when no search is running, or when pressed twice.
+
+use std::collections::hash_map::DefaultHasher;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::io;
+use std::marker::PhantomData;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::{PuzzleError, Result};
+
+/// Time between periodic saves
+pub const INTERVAL: Duration = Duration::from_secs(10);
+
+#[derive(Debug, Clone)]
+struct Config {
+    dir: PathBuf,
+    resume: bool,
+}
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// Number of checkpoints that are on
+static ACTIVE: AtomicUsize = AtomicUsize::new(0);
+
+/// Save checkpoints in `dir`, resuming from them if `resume`, and catch
+/// Ctrl-C so that searches can save before exiting
+pub fn enable(dir: &Path, resume: bool) -> Result<()> {
+    let config = Config {
+        dir: dir.to_path_buf(),
+        resume,
+    };
+    if CONFIG.set(config).is_ok() {
+        ctrlc::set_handler(|| {
+            if INTERRUPTED.swap(true, Ordering::SeqCst) || ACTIVE.load(Ordering::SeqCst) == 0 {
+                std::process::exit(130);
+            }
+        })?;
+    }
+    Ok(())
+}
+
+/// [`enable`] in `target/checkpoints`, resuming if the program got a
+/// `--resume` argument
+pub fn enable_from_args() -> Result<()> {
+    let resume = std::env::args().skip(1).any(|arg| arg == "--resume");
+    enable(Path::new("target/checkpoints"), resume)
+}
+
+/// Checkpoint of the state `T` of a search
+#[derive(Debug)]
+pub struct Checkpoint<T> {
+    path: Option<PathBuf>,
+    resume: bool,
+    last: Instant,
+    state: PhantomData<T>,
+}
+
+impl<T: Serialize + DeserializeOwned> Checkpoint<T> {
+    /// Checkpoint of the search `name` (e.g. `day17b`) of `key`, which
+    /// is usually the parsed input, with the given `version` of the state
+    pub fn new(name: &str, version: u32, key: &impl Serialize) -> Result<Checkpoint<T>> {
+        Ok(match CONFIG.get() {
+            Some(config) => Checkpoint::in_dir(&config.dir, config.resume, name, version, key)?,
+            None => Checkpoint {
+                path: None,
+                resume: false,
+                last: Instant::now(),
+                state: PhantomData,
+            },
+        })
+    }
+
+    pub fn in_dir(
+        dir: &Path,
+        resume: bool,
+        name: &str,
+        version: u32,
+        key: &impl Serialize,
+    ) -> Result<Checkpoint<T>> {
+        let mut hasher = DefaultHasher::new();
+        serde_json::to_string(key)?.hash(&mut hasher);
+        let path = dir.join(format!(
+            "{}-v{}-{:016x}.json",
+            name,
+            version,
+            hasher.finish()
+        ));
+        ACTIVE.fetch_add(1, Ordering::SeqCst);
+        Ok(Checkpoint {
+            path: Some(path),
+            resume,
+            last: Instant::now(),
+            state: PhantomData,
+        })
+    }
+
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// State saved by the previous run, if resuming and there is one
+    pub fn resume(&self) -> Result<Option<T>> {
+        let Some(path) = self.path.as_ref().filter(|_| self.resume) else {
+            return Ok(None);
+        };
+        match fs::read_to_string(path) {
+            Ok(json) => Ok(Some(serde_json::from_str(&json)?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save(&mut self, state: &T) -> Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        // Write to the side and rename, so that a kill while writing
+        // doesn't lose the previous checkpoint
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string(state)?)?;
+        fs::rename(&tmp, path)?;
+        self.last = Instant::now();
+        Ok(())
+    }
+
+    /// Save the state if it's time to, or if we got a Ctrl-C, in which
+    /// case the search should return the error
+    pub fn tick(&mut self, state: impl FnOnce() -> T) -> Result<()> {
+        if self.path.is_none() {
+            return Ok(());
+        }
+        let interrupted = INTERRUPTED.load(Ordering::Relaxed);
+        if interrupted || self.last.elapsed() >= INTERVAL {
+            self.save(&state())?;
+        }
+        if let Some(path) = self.path.as_ref().filter(|_| interrupted) {
+            return Err(PuzzleError::interrupted(format!(
+                "state saved to {}, use --resume to continue",
+                path.display()
+            ))
+            .into());
+        }
+        Ok(())
+    }
+
+    /// The search finished, the checkpoint is no longer needed
+    pub fn done(self) -> Result<()> {
+        match self.path.as_ref().map(fs::remove_file) {
+            Some(Err(e)) if e.kind() != io::ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<T> Drop for Checkpoint<T> {
+    fn drop(&mut self) {
+        if self.path.is_some() {
+            ACTIVE.fetch_sub(1, Ordering::SeqCst);
+        }
+    }
+}
+
+#[test]
+fn test_checkpoint() -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("aoc-checkpoint-{}", std::process::id()));
+    let mut checkpoint = Checkpoint::<Vec<u32>>::in_dir(&dir, true, "day00a", 1, &"input")?;
+    assert_eq!(checkpoint.resume()?, None);
+    checkpoint.tick(|| unreachable!())?;
+    checkpoint.save(&vec![1, 2, 3])?;
+    let other = Checkpoint::<Vec<u32>>::in_dir(&dir, true, "day00a", 1, &"other input")?;
+    assert_eq!(other.resume()?, None);
+    let fresh = Checkpoint::<Vec<u32>>::in_dir(&dir, false, "day00a", 1, &"input")?;
+    assert_eq!(fresh.resume()?, None);
+    let resumed = Checkpoint::<Vec<u32>>::in_dir(&dir, true, "day00a", 1, &"input")?;
+    assert_eq!(resumed.resume()?, Some(vec![1, 2, 3]));
+    // States of another version are not resumed
+    let newer = Checkpoint::<Vec<u32>>::in_dir(&dir, true, "day00a", 2, &"input")?;
+    assert_eq!(newer.resume()?, None);
+    resumed.done()?;
+    assert_eq!(checkpoint.resume()?, None);
+    // Without enable, checkpoints are off
+    let off = Checkpoint::<Vec<u32>>::new("day00a", 1, &"input")?;
+    assert_eq!(off.path(), None);
+    // Ctrl-C
+    let mut checkpoint = Checkpoint::<u32>::in_dir(&dir, true, "day00b", 1, &0)?;
+    INTERRUPTED.store(true, Ordering::SeqCst);
+    let result = checkpoint.tick(|| 42);
+    INTERRUPTED.store(false, Ordering::SeqCst);
+    let report = result.unwrap_err();
+    assert_eq!(crate::exit_code(&report), 130);
+    assert_eq!(checkpoint.resume()?, Some(42));
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -0,0 +1,441 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Collections for the state of hot loops
+//!
+//! std's maps hash with SipHash, which resists flooding but is slow for
+//! the small keys of the puzzles; [`FastHashMap`] and [`FastHashSet`]
+//! use [`FastHasher`] instead, which is also deterministic.
+//!
+//! Positions of a grid are already dense indexes, with
+//! `PosT::to_usize`, so sets and maps keyed by them, or by them and a
+//! [`Dir`], are plain bitsets and vectors sized by the grid.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::{BuildHasherDefault, Hasher};
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+
+use sqrid::postrait::PosT;
+use sqrid::Dir;
+
+/// Multiply-rotate hasher, the one rustc uses for its own tables
+///
+/// Not resistant to collision attacks, which we don't have to worry about.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FastHasher(u64);
+
+const SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+impl FastHasher {
+    #[inline]
+    fn add(&mut self, word: u64) {
+        self.0 = (self.0.rotate_left(5) ^ word).wrapping_mul(SEED);
+    }
+}
+
+impl Hasher for FastHasher {
+    #[inline]
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        for &byte in chunks.remainder() {
+            self.add(byte as u64);
+        }
+    }
+
+    #[inline]
+    fn write_u8(&mut self, i: u8) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn write_u16(&mut self, i: u16) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn write_u32(&mut self, i: u32) {
+        self.add(i as u64);
+    }
+
+    #[inline]
+    fn write_u64(&mut self, i: u64) {
+        self.add(i);
+    }
+
+    #[inline]
+    fn write_usize(&mut self, i: usize) {
+        self.add(i as u64);
+    }
+}
+
+pub type FastBuildHasher = BuildHasherDefault<FastHasher>;
+
+/// `HashMap` with [`FastHasher`]; create it with `default()`
+pub type FastHashMap<K, V> = HashMap<K, V, FastBuildHasher>;
+
+/// `HashSet` with [`FastHasher`]; create it with `default()`
+pub type FastHashSet<K> = HashSet<K, FastBuildHasher>;
+
+/* Bitset */
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Bitset {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Bitset {
+    fn new(size: usize) -> Bitset {
+        Bitset {
+            words: vec![0; size.div_ceil(64)],
+            len: 0,
+        }
+    }
+
+    #[inline]
+    fn contains(&self, i: usize) -> bool {
+        self.words[i / 64] & (1 << (i % 64)) != 0
+    }
+
+    #[inline]
+    fn insert(&mut self, i: usize) -> bool {
+        let word = &mut self.words[i / 64];
+        let bit = 1 << (i % 64);
+        let new = *word & bit == 0;
+        *word |= bit;
+        self.len += usize::from(new);
+        new
+    }
+
+    #[inline]
+    fn remove(&mut self, i: usize) -> bool {
+        let word = &mut self.words[i / 64];
+        let bit = 1 << (i % 64);
+        let had = *word & bit != 0;
+        *word &= !bit;
+        self.len -= usize::from(had);
+        had
+    }
+
+    fn clear(&mut self) {
+        self.words.fill(0);
+        self.len = 0;
+    }
+
+    fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                (word != 0).then(|| {
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    i * 64 + bit
+                })
+            })
+        })
+    }
+}
+
+/// Index of a position and a direction in the dense collections
+#[inline]
+fn posdir_index<P: PosT>(pos: P, dir: Dir) -> usize {
+    pos.to_usize() * Dir::SIZE + dir as usize
+}
+
+/* PosSet */
+
+/// Set of positions of a grid, as a bitset
+#[derive(Clone, PartialEq, Eq)]
+pub struct PosSet<P> {
+    bits: Bitset,
+    pos: PhantomData<P>,
+}
+
+impl<P: PosT> PosSet<P> {
+    pub fn new() -> PosSet<P> {
+        PosSet {
+            bits: Bitset::new(P::dimensions()),
+            pos: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn contains(&self, pos: &P) -> bool {
+        self.bits.contains(pos.to_usize())
+    }
+
+    /// Add the position; false if it was already there
+    #[inline]
+    pub fn insert(&mut self, pos: P) -> bool {
+        self.bits.insert(pos.to_usize())
+    }
+
+    /// Remove the position; false if it wasn't there
+    #[inline]
+    pub fn remove(&mut self, pos: &P) -> bool {
+        self.bits.remove(pos.to_usize())
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear()
+    }
+
+    /// The positions, in `to_usize` order
+    pub fn iter(&self) -> impl Iterator<Item = P> + '_ {
+        self.bits
+            .iter()
+            .map(|i| P::tryfrom_usize(i).expect("index of a position"))
+    }
+}
+
+impl<P: PosT> Default for PosSet<P> {
+    fn default() -> PosSet<P> {
+        PosSet::new()
+    }
+}
+
+impl<P: PosT> fmt::Debug for PosSet<P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<P: PosT> Extend<P> for PosSet<P> {
+    fn extend<I: IntoIterator<Item = P>>(&mut self, iter: I) {
+        for pos in iter {
+            self.insert(pos);
+        }
+    }
+}
+
+impl<P: PosT> FromIterator<P> for PosSet<P> {
+    fn from_iter<I: IntoIterator<Item = P>>(iter: I) -> PosSet<P> {
+        let mut set = PosSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+/* PosDirSet */
+
+/// Set of positions of a grid with a direction, as a bitset
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PosDirSet<P> {
+    bits: Bitset,
+    pos: PhantomData<P>,
+}
+
+impl<P: PosT> PosDirSet<P> {
+    pub fn new() -> PosDirSet<P> {
+        PosDirSet {
+            bits: Bitset::new(P::dimensions() * Dir::SIZE),
+            pos: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn contains(&self, pos: &P, dir: Dir) -> bool {
+        self.bits.contains(posdir_index(*pos, dir))
+    }
+
+    /// Add the position and direction; false if they were already there
+    #[inline]
+    pub fn insert(&mut self, pos: P, dir: Dir) -> bool {
+        self.bits.insert(posdir_index(pos, dir))
+    }
+
+    #[inline]
+    pub fn remove(&mut self, pos: &P, dir: Dir) -> bool {
+        self.bits.remove(posdir_index(*pos, dir))
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        self.bits.clear()
+    }
+}
+
+impl<P: PosT> Default for PosDirSet<P> {
+    fn default() -> PosDirSet<P> {
+        PosDirSet::new()
+    }
+}
+
+/* PosMap */
+
+/// Value for every position of a grid, as a vector
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PosMap<P, T> {
+    values: Vec<T>,
+    pos: PhantomData<P>,
+}
+
+impl<P: PosT, T: Clone> PosMap<P, T> {
+    /// Every position starts with `value`
+    pub fn new(value: T) -> PosMap<P, T> {
+        PosMap {
+            values: vec![value; P::dimensions()],
+            pos: PhantomData,
+        }
+    }
+
+    /// Set every position back to `value`
+    pub fn fill(&mut self, value: T) {
+        self.values.fill(value)
+    }
+}
+
+impl<P: PosT, T> PosMap<P, T> {
+    /// Positions and values, in `to_usize` order
+    pub fn iter(&self) -> impl Iterator<Item = (P, &T)> + '_ {
+        self.values.iter().enumerate().map(|(i, value)| {
+            let pos = P::tryfrom_usize(i).expect("index of a position");
+            (pos, value)
+        })
+    }
+}
+
+impl<P: PosT, T> Index<P> for PosMap<P, T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, pos: P) -> &T {
+        &self.values[pos.to_usize()]
+    }
+}
+
+impl<P: PosT, T> IndexMut<P> for PosMap<P, T> {
+    #[inline]
+    fn index_mut(&mut self, pos: P) -> &mut T {
+        &mut self.values[pos.to_usize()]
+    }
+}
+
+/* PosDirMap */
+
+/// Value for every position of a grid and direction, as a vector
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PosDirMap<P, T> {
+    values: Vec<T>,
+    pos: PhantomData<P>,
+}
+
+impl<P: PosT, T: Clone> PosDirMap<P, T> {
+    /// Every position and direction starts with `value`
+    pub fn new(value: T) -> PosDirMap<P, T> {
+        PosDirMap {
+            values: vec![value; P::dimensions() * Dir::SIZE],
+            pos: PhantomData,
+        }
+    }
+
+    /// Set every position and direction back to `value`
+    pub fn fill(&mut self, value: T) {
+        self.values.fill(value)
+    }
+}
+
+impl<P: PosT, T> Index<(P, Dir)> for PosDirMap<P, T> {
+    type Output = T;
+    #[inline]
+    fn index(&self, (pos, dir): (P, Dir)) -> &T {
+        &self.values[posdir_index(pos, dir)]
+    }
+}
+
+impl<P: PosT, T> IndexMut<(P, Dir)> for PosDirMap<P, T> {
+    #[inline]
+    fn index_mut(&mut self, (pos, dir): (P, Dir)) -> &mut T {
+        &mut self.values[posdir_index(pos, dir)]
+    }
+}
+
+#[cfg(test)]
+type TestPos = sqrid::pos_create!(sqrid::sqrid_create!(9, 7, false));
+
+#[test]
+fn test_fast_hash() {
+    use std::hash::BuildHasher;
+    let build = FastBuildHasher::default();
+    assert_eq!(
+        build.hash_one([1_i8, -2, 3, 0]),
+        build.hash_one([1_i8, -2, 3, 0])
+    );
+    assert_ne!(
+        build.hash_one([1_i8, -2, 3, 0]),
+        build.hash_one([1_i8, -2, 0, 3])
+    );
+    assert_ne!(build.hash_one("abcdefghi"), build.hash_one("abcdefghj"));
+    let mut map = FastHashMap::<(u16, u16), char>::default();
+    map.insert((1, 2), 'a');
+    *map.entry((1, 2)).or_default() = 'b';
+    assert_eq!(map[&(1, 2)], 'b');
+}
+
+#[test]
+fn test_posset() {
+    let mut set = PosSet::<TestPos>::new();
+    let last = TestPos::new_unwrap(9, 7);
+    assert!(set.is_empty());
+    assert!(set.insert(last));
+    assert!(!set.insert(last));
+    assert!(set.insert(TestPos::new_unwrap(3, 0)));
+    assert!(set.contains(&last));
+    assert!(!set.contains(&TestPos::new_unwrap(2, 0)));
+    assert_eq!(set.len(), 2);
+    assert_eq!(
+        set.iter().collect::<Vec<_>>(),
+        vec![TestPos::new_unwrap(3, 0), last]
+    );
+    assert!(set.remove(&last));
+    assert!(!set.remove(&last));
+    assert_eq!(set.len(), 1);
+    assert_eq!(TestPos::iter().collect::<PosSet<_>>().len(), 80);
+    set.clear();
+    assert!(set.is_empty());
+}
+
+#[test]
+fn test_posdir() {
+    let pos = TestPos::new_unwrap(4, 5);
+    let mut set = PosDirSet::<TestPos>::new();
+    assert!(set.insert(pos, Dir::E));
+    assert!(!set.insert(pos, Dir::E));
+    assert!(!set.contains(&pos, Dir::W));
+    assert!(set.insert(TestPos::new_unwrap(9, 7), Dir::NW));
+    assert_eq!(set.len(), 2);
+    let mut map = PosDirMap::<TestPos, usize>::new(usize::MAX);
+    map[(pos, Dir::S)] = 3;
+    assert_eq!(map[(pos, Dir::S)], 3);
+    assert_eq!(map[(pos, Dir::N)], usize::MAX);
+    let mut map = PosMap::<TestPos, u8>::new(0);
+    map[pos] += 2;
+    assert_eq!(
+        map.iter().filter(|(_, v)| **v > 0).collect::<Vec<_>>(),
+        vec![(pos, &2)]
+    );
+}
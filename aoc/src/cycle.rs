@@ -0,0 +1,187 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Cycle detection in state machines
+//!
+//! The functions here follow the sequence `x0, step(x0), step(step(x0))...`
+//! until a state repeats. `step` returns None when the simulation ends,
+//! e.g. when the guard leaves the map, in which case there is no cycle.
+//!
+//! [`brent`] and [`floyd`] only keep a couple of states around, but call
+//! `step` a few times per state; [`hashed`] calls `step` once per state,
+//! but keeps all of them in a HashMap.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Position of the cycle in the sequence of states
+///
+/// The states at indexes `start` and `start + len` are the same, and
+/// `len` is the smallest number for which that holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle {
+    pub start: usize,
+    pub len: usize,
+}
+
+impl Cycle {
+    /// Smallest index with the same state as index `n`
+    pub fn index_of(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.len
+        }
+    }
+}
+
+/// Brent's algorithm: finds the cycle length with power-of-two jumps
+pub fn brent<S, F>(x0: S, mut step: F) -> Option<Cycle>
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = x0.clone();
+    let mut hare = step(&x0)?;
+    while tortoise != hare {
+        if power == len {
+            tortoise = hare.clone();
+            power *= 2;
+            len = 0;
+        }
+        hare = step(&hare)?;
+        len += 1;
+    }
+    // Place the hare len steps ahead and walk both until they meet at the
+    // start of the cycle:
+    let mut tortoise = x0.clone();
+    let mut hare = x0;
+    for _ in 0..len {
+        hare = step(&hare)?;
+    }
+    let mut start = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+    Some(Cycle { start, len })
+}
+
+/// Floyd's tortoise and hare
+pub fn floyd<S, F>(x0: S, mut step: F) -> Option<Cycle>
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut tortoise = step(&x0)?;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        let half = step(&hare)?;
+        hare = step(&half)?;
+    }
+    let mut start = 0;
+    let mut tortoise = x0;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+    let mut len = 1;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        hare = step(&hare)?;
+        len += 1;
+    }
+    Some(Cycle { start, len })
+}
+
+/// Remember the index of every state until one repeats
+pub fn hashed<S, F>(x0: S, mut step: F) -> Option<Cycle>
+where
+    S: Eq + Hash,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut seen = HashMap::<S, usize>::default();
+    let mut x = x0;
+    for i in 0.. {
+        if let Some(&start) = seen.get(&x) {
+            return Some(Cycle {
+                start,
+                len: i - start,
+            });
+        }
+        let next = step(&x)?;
+        seen.insert(x, i);
+        x = next;
+    }
+    unreachable!()
+}
+
+/// The state at index `n`, or None if the simulation ends before that
+///
+/// Looks for a cycle with [`brent`] for at most `n` steps, so that it
+/// takes `O(min(n, start + len))` steps no matter how big `n` is, and
+/// also works for sequences that neither end nor repeat.
+pub fn nth<S, F>(x0: S, mut step: F, n: usize) -> Option<S>
+where
+    S: Clone + Eq,
+    F: FnMut(&S) -> Option<S>,
+{
+    let mut budget = n;
+    let cycle = brent(x0.clone(), |x| {
+        budget = budget.checked_sub(1)?;
+        step(x)
+    });
+    let n = cycle.map_or(n, |cycle| cycle.index_of(n));
+    (0..n).try_fold(x0, |x, _| step(&x))
+}
+
+#[cfg(test)]
+fn test_step(x: &u64) -> Option<u64> {
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 5 -> 6 -> 2 ...
+    Some(if *x == 6 { 2 } else { x + 1 })
+}
+
+#[test]
+fn test_detectors() {
+    let expected = Some(Cycle { start: 2, len: 5 });
+    assert_eq!(brent(0, test_step), expected);
+    assert_eq!(floyd(0, test_step), expected);
+    assert_eq!(hashed(0, test_step), expected);
+    // Starting inside the cycle
+    let expected = Some(Cycle { start: 0, len: 5 });
+    assert_eq!(brent(4, test_step), expected);
+    assert_eq!(floyd(4, test_step), expected);
+    assert_eq!(hashed(4, test_step), expected);
+    // Fixed point
+    let expected = Some(Cycle { start: 3, len: 1 });
+    let step = |x: &i32| Some((x + 1).min(3));
+    assert_eq!(brent(0, step), expected);
+    assert_eq!(floyd(0, step), expected);
+    assert_eq!(hashed(0, step), expected);
+    // Simulation that ends
+    let step = |x: &i32| (*x < 10).then_some(x + 1);
+    assert_eq!(brent(0, step), None);
+    assert_eq!(floyd(0, step), None);
+    assert_eq!(hashed(0, step), None);
+}
+
+#[test]
+fn test_nth() {
+    let cycle = Cycle { start: 2, len: 5 };
+    assert_eq!(cycle.index_of(1), 1);
+    assert_eq!(cycle.index_of(7), 2);
+    assert_eq!(cycle.index_of(1_000_000_000_003), 3);
+    assert_eq!(nth(0, test_step, 1_000_000_000_003), Some(3));
+    assert_eq!(nth(0, test_step, 4), Some(4));
+    let step = |x: &i32| (*x < 10).then_some(x + 1);
+    assert_eq!(nth(0, step, 10), Some(10));
+    assert_eq!(nth(0, step, 11), None);
+    // Neither ends nor repeats
+    assert_eq!(nth(0_u64, |x| Some(x + 1), 5), Some(5));
+}
@@ -0,0 +1,172 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Disjoint-set union, a.k.a. union-find
+//!
+//! Elements are the integers in `0..len`; grid positions map to them with
+//! `usize::from(pos)`, which is what [`grid_regions`] does.
+
+use std::collections::BTreeMap;
+
+use sqrid::{Dir, Grid, Pos};
+
+#[derive(Debug, Clone, Default)]
+pub struct Dsu {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+    size: Vec<usize>,
+    count: usize,
+}
+
+impl Dsu {
+    /// `len` elements, each in its own set
+    pub fn new(len: usize) -> Dsu {
+        Dsu {
+            parent: (0..len).collect(),
+            rank: vec![0; len],
+            size: vec![1; len],
+            count: len,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Representative of the set of `x`, with path compression
+    pub fn find(&mut self, x: usize) -> usize {
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        let mut x = x;
+        while self.parent[x] != root {
+            (x, self.parent[x]) = (self.parent[x], root);
+        }
+        root
+    }
+
+    /// Merge the sets of `a` and `b`; false if they were already the same
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.rank[a] < self.rank[b] {
+            (a, b) = (b, a);
+        }
+        if self.rank[a] == self.rank[b] {
+            self.rank[a] += 1;
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.count -= 1;
+        true
+    }
+
+    pub fn same(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of elements in the set of `x`
+    pub fn size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    /// Number of disjoint sets
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// All sets, each one sorted, ordered by their smallest element
+    pub fn components(&mut self) -> Vec<Vec<usize>> {
+        let mut sets = BTreeMap::<usize, Vec<usize>>::default();
+        let mut first = vec![usize::MAX; self.len()];
+        for x in 0..self.len() {
+            let root = self.find(x);
+            if first[root] == usize::MAX {
+                first[root] = x;
+            }
+            sets.entry(first[root]).or_default().push(x);
+        }
+        sets.into_values().collect()
+    }
+}
+
+/// Union of the 4-connected (or 8-connected, with `diagonals`) regions
+/// of the grid in which neighbors are `same`
+///
+/// Each position is the element `usize::from(pos)` of the result.
+pub fn grid_regions<T, F, const XMAX: u16, const YMAX: u16, const SIZE: usize>(
+    grid: &Grid<T, Pos<XMAX, YMAX>, SIZE>,
+    diagonals: bool,
+    same: F,
+) -> Dsu
+where
+    F: Fn(&T, &T) -> bool,
+{
+    // Looking forward is enough, the other directions are covered when
+    // we get to the neighbor
+    let dirs: &[Dir] = if diagonals {
+        &[Dir::E, Dir::SE, Dir::S, Dir::SW]
+    } else {
+        &[Dir::E, Dir::S]
+    };
+    let mut dsu = Dsu::new(SIZE);
+    for (pos, cell) in grid.iter_pos() {
+        for &dir in dirs {
+            let Ok(other) = pos + dir else {
+                continue;
+            };
+            if same(cell, &grid[other]) {
+                dsu.union(usize::from(pos), usize::from(other));
+            }
+        }
+    }
+    dsu
+}
+
+#[test]
+fn test_dsu() {
+    let mut dsu = Dsu::new(6);
+    assert!(dsu.union(0, 1));
+    assert!(dsu.union(3, 4));
+    assert!(dsu.union(1, 4));
+    assert!(!dsu.union(0, 3));
+    assert!(dsu.same(0, 4));
+    assert!(!dsu.same(0, 2));
+    assert_eq!(dsu.size(3), 4);
+    assert_eq!(dsu.size(5), 1);
+    assert_eq!(dsu.count(), 3);
+    assert_eq!(dsu.components(), vec![vec![0, 1, 3, 4], vec![2], vec![5]]);
+}
+
+#[test]
+fn test_grid_regions() -> Result<(), sqrid::Error> {
+    type Sqrid = sqrid::sqrid_create!(3, 2, false);
+    type Pos = sqrid::pos_create!(Sqrid);
+    type Grid = sqrid::grid_create!(Sqrid, char);
+    let mut grid = Grid::default();
+    grid.extend_from_vecvec(vec![
+        "AAB.".chars().collect(),
+        "BBA.".chars().collect(),
+        "B.AA".chars().collect(),
+    ])?;
+    let pos = |x: u16, y: u16| Pos::new(x, y).map(usize::from);
+    let mut dsu = grid_regions(&grid, false, |a, b| a == b);
+    assert_eq!(dsu.count(), 6);
+    assert_eq!(dsu.size(pos(0, 0)?), 2);
+    assert_eq!(dsu.size(pos(2, 1)?), 3);
+    assert!(!dsu.same(pos(2, 0)?, pos(1, 1)?));
+    let mut dsu = grid_regions(&grid, true, |a, b| a == b);
+    assert_eq!(dsu.count(), 4);
+    assert!(dsu.same(pos(2, 0)?, pos(1, 1)?));
+    assert!(dsu.same(pos(0, 0)?, pos(3, 2)?));
+    Ok(())
+}
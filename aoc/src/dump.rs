@@ -0,0 +1,210 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! JSON form of the parsed inputs
+//!
+//! What the grammar of each day produces - the output of
+//! [`parse_with!`](crate::parse_with) - is serializable, and the day's
+//! [`Dumper`] prints it for `aoc dump`. Going the other way,
+//! [`Dumper::load`] writes the loaded data back as an input, so that the
+//! solvers run on a dump without knowing about JSON:
+//!
+//! ```sh
+//! aoc dump 24 > day24.json
+//! aoc run 24 --json --input day24.json
+//! ```
+//!
+//! The checks that the day's parser does after the grammar also run on
+//! the loaded data.
+
+use std::fmt;
+use std::io::BufRead;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::error::{Location, PuzzleError};
+use crate::Result;
+
+pub use serde;
+pub use serde_json;
+
+/// Pretty-printed JSON of `value`
+pub fn to_json<T: Serialize + ?Sized>(value: &T) -> Result<String> {
+    let mut json = serde_json::to_string_pretty(value)?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// Data loaded from JSON, with errors pointing at where it's invalid
+pub fn from_json<T: DeserializeOwned>(json: &str) -> Result<T> {
+    serde_json::from_str(json).map_err(|e| {
+        PuzzleError::invalid_input_at(
+            Location {
+                line: e.line(),
+                column: e.column(),
+            },
+            format!("invalid JSON: {}", e),
+        )
+        .into()
+    })
+}
+
+/// [`from_json`] into the type that `parse` returns, so that the
+/// [`dumper!`](crate::dumper) of a day doesn't have to name it
+pub fn from_json_for<T: DeserializeOwned>(
+    json: &str,
+    _parse: impl FnOnce(&mut dyn BufRead) -> Result<T>,
+) -> Result<T> {
+    from_json(json)
+}
+
+/// Text of a grid, a line per row, with `cell` writing each cell
+pub fn grid_text<T, D: fmt::Display>(grid: &[Vec<T>], cell: impl Fn(&T) -> D) -> String {
+    grid.iter()
+        .map(|line| {
+            line.iter()
+                .map(&cell)
+                .map(|c| c.to_string())
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+/// Outline of a JSON value, e.g. `[130 × [130 × string]]` for a grid
+///
+/// Arrays with elements of different shapes, like tuples, list them all.
+pub fn shape(value: &serde_json::Value) -> String {
+    use serde_json::Value;
+    match value {
+        Value::Null => "null".to_string(),
+        Value::Bool(_) => "bool".to_string(),
+        Value::Number(_) => "number".to_string(),
+        Value::String(_) => "string".to_string(),
+        Value::Array(items) => {
+            let shapes = items.iter().map(shape).collect::<Vec<_>>();
+            match shapes.first() {
+                None => "[]".to_string(),
+                Some(first) if shapes.iter().all(|s| s == first) => {
+                    format!("[{} × {}]", shapes.len(), first)
+                }
+                Some(_) => format!("[{}]", shapes.join(", ")),
+            }
+        }
+        Value::Object(fields) => {
+            let fields = fields
+                .iter()
+                .map(|(name, value)| format!("{}: {}", name, shape(value)))
+                .collect::<Vec<_>>();
+            format!("{{{}}}", fields.join(", "))
+        }
+    }
+}
+
+/// JSON form of the inputs of one day
+#[derive(Clone, Copy)]
+pub struct Dumper {
+    pub day: u32,
+    /// Parses the input and returns the JSON of the grammar output
+    pub dump: fn(&mut dyn BufRead) -> Result<String>,
+    /// Input whose grammar output is the given JSON
+    pub load: fn(&str) -> Result<String>,
+}
+
+impl fmt::Debug for Dumper {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Dumper")
+            .field("day", &self.day)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Declare the `DUMPER` of a day, given the parser function that
+/// returns the output of `parse_with!` and the function that writes that
+/// output back as text
+///
+/// ```ignore
+/// dumper! {
+///     day: 6,
+///     parser::parse0,
+///     parser::unparse,
+/// }
+/// ```
+#[macro_export]
+macro_rules! dumper {
+    (day: $day:literal, $parse:path, $unparse:path $(,)?) => {
+        pub const DUMPER: $crate::dump::Dumper = $crate::dump::Dumper {
+            day: $day,
+            dump: |bufin| $crate::dump::to_json(&$parse(bufin)?),
+            load: |json| {
+                let loaded = $crate::dump::from_json_for(json, |bufin| $parse(bufin))?;
+                Ok($unparse(&loaded))
+            },
+        };
+    };
+}
+
+/// `#[serde(with = "aoc::dump::dir")]` for `sqrid::Dir` fields, which
+/// become their cardinal names: `"N"`, `"NE"`, etc.
+pub mod dir {
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+    use sqrid::Dir;
+
+    pub fn serialize<S: Serializer>(dir: &Dir, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(dir.name_cardinal())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Dir, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Dir::iter::<true>()
+            .find(|dir| dir.name_cardinal() == name)
+            .ok_or_else(|| D::Error::custom(format!("invalid direction {:?}", name)))
+    }
+}
+
+#[test]
+fn test_from_json() -> Result<()> {
+    let json = to_json(&vec![(1, 'a'), (2, 'b')])?;
+    let loaded = from_json::<Vec<(u32, char)>>(&json)?;
+    assert_eq!(loaded, vec![(1, 'a'), (2, 'b')]);
+    let report = from_json::<Vec<u32>>("[1,\n x]").unwrap_err();
+    assert!(matches!(
+        report.downcast_ref::<PuzzleError>(),
+        Some(PuzzleError::InvalidInput {
+            location: Some(Location { line: 2, column: 2 }),
+            ..
+        })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_grid_text() {
+    let grid = vec![vec![1, 0], vec![0, 1]];
+    assert_eq!(
+        grid_text(&grid, |c| if *c == 1 { '#' } else { '.' }),
+        "#.\n.#\n"
+    );
+}
+
+#[test]
+fn test_shape() -> Result<()> {
+    let value = serde_json::json!([[["x00", true], ["x01", false]], [1, 2, 3]]);
+    assert_eq!(shape(&value), "[[2 × [string, bool]], [3 × number]]");
+    let value = serde_json::json!({"regs": {"a": 1}, "prog": []});
+    assert_eq!(shape(&value), "{prog: [], regs: {a: number}}");
+    Ok(())
+}
+
+#[test]
+fn test_dir() -> Result<()> {
+    #[derive(Debug, PartialEq, Serialize, serde::Deserialize)]
+    struct Step(#[serde(with = "dir")] sqrid::Dir);
+    assert_eq!(serde_json::to_string(&Step(sqrid::Dir::NE))?, "\"NE\"");
+    assert_eq!(serde_json::from_str::<Step>("\"W\"")?, Step(sqrid::Dir::W));
+    assert!(serde_json::from_str::<Step>("\"X\"").is_err());
+    Ok(())
+}
@@ -0,0 +1,171 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use std::fmt::{self, Display};
+
+use color_eyre::Report;
+
+/// Position in the puzzle input, both 1-based
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Location {
+    /// Location of the cell at 0-based `x`, `y` of a grid that starts at
+    /// the beginning of the input
+    pub fn from_xy(x: usize, y: usize) -> Location {
+        Location {
+            line: y + 1,
+            column: x + 1,
+        }
+    }
+
+    /// Location of `rest` inside `input`, where `rest` is a suffix of
+    /// `input` - usually what the parser could not consume.
+    pub fn of_suffix(input: &str, rest: &str) -> Location {
+        let offset = input.len().saturating_sub(rest.len());
+        let consumed = &input[..offset];
+        let line = consumed.matches('\n').count() + 1;
+        let column = consumed
+            .rfind('\n')
+            .map_or(consumed, |nl| &consumed[nl + 1..])
+            .chars()
+            .count()
+            + 1;
+        Location { line, column }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.column)
+    }
+}
+
+/// Expected ways in which a puzzle solver can fail
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PuzzleError {
+    /// The input is valid, but there is no answer to be found
+    NoSolution(String),
+    /// The input is malformed or breaks the puzzle rules
+    InvalidInput {
+        location: Option<Location>,
+        message: String,
+    },
+    /// A simulation or dependency chain loops back on itself
+    Cycle(String),
+    /// A value does not fit in the type used by the solver
+    Overflow(String),
+    /// The input is valid, but exceeds what the solver was built for,
+    /// e.g. a grid larger than its fixed size
+    Unsupported(String),
+    /// The search was stopped with Ctrl-C, after saving a checkpoint
+    Interrupted(String),
+}
+
+impl PuzzleError {
+    pub fn no_solution<M: Display>(message: M) -> Self {
+        PuzzleError::NoSolution(message.to_string())
+    }
+
+    pub fn invalid_input<M: Display>(message: M) -> Self {
+        PuzzleError::InvalidInput {
+            location: None,
+            message: message.to_string(),
+        }
+    }
+
+    pub fn invalid_input_at<M: Display>(location: Location, message: M) -> Self {
+        PuzzleError::InvalidInput {
+            location: Some(location),
+            message: message.to_string(),
+        }
+    }
+
+    pub fn cycle<M: Display>(message: M) -> Self {
+        PuzzleError::Cycle(message.to_string())
+    }
+
+    pub fn overflow<M: Display>(message: M) -> Self {
+        PuzzleError::Overflow(message.to_string())
+    }
+
+    pub fn unsupported<M: Display>(message: M) -> Self {
+        PuzzleError::Unsupported(message.to_string())
+    }
+
+    pub fn interrupted<M: Display>(message: M) -> Self {
+        PuzzleError::Interrupted(message.to_string())
+    }
+
+    /// Process exit code used by the runner for this error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            PuzzleError::InvalidInput { .. } => 2,
+            PuzzleError::NoSolution(_) => 3,
+            PuzzleError::Cycle(_) => 4,
+            PuzzleError::Overflow(_) => 5,
+            PuzzleError::Unsupported(_) => 6,
+            PuzzleError::Interrupted(_) => 130,
+        }
+    }
+}
+
+impl Display for PuzzleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PuzzleError::NoSolution(m) => write!(f, "no solution: {}", m),
+            PuzzleError::InvalidInput {
+                location: Some(location),
+                message,
+            } => write!(f, "invalid input at {}: {}", location, message),
+            PuzzleError::InvalidInput {
+                location: None,
+                message,
+            } => write!(f, "invalid input: {}", message),
+            PuzzleError::Cycle(m) => write!(f, "cycle detected: {}", m),
+            PuzzleError::Overflow(m) => write!(f, "overflow: {}", m),
+            PuzzleError::Unsupported(m) => write!(f, "unsupported input: {}", m),
+            PuzzleError::Interrupted(m) => write!(f, "interrupted: {}", m),
+        }
+    }
+}
+
+impl std::error::Error for PuzzleError {}
+
+/// Exit code for any error: the one of the PuzzleError in its chain, or 1
+pub fn exit_code(report: &Report) -> i32 {
+    report
+        .chain()
+        .find_map(|e| e.downcast_ref::<PuzzleError>())
+        .map_or(1, PuzzleError::exit_code)
+}
+
+#[test]
+fn test_location() {
+    let input = "abc\nde\nfgh\n";
+    assert_eq!(
+        Location::of_suffix(input, input),
+        Location { line: 1, column: 1 }
+    );
+    assert_eq!(
+        Location::of_suffix(input, &input[5..]),
+        Location { line: 2, column: 2 }
+    );
+    assert_eq!(
+        Location::of_suffix(input, ""),
+        Location { line: 4, column: 1 }
+    );
+}
+
+#[test]
+fn test_exit_code() {
+    let report = Report::from(PuzzleError::cycle("a -> b -> a"));
+    assert_eq!(exit_code(&report), 4);
+    let report = report.wrap_err("while solving");
+    assert_eq!(exit_code(&report), 4);
+    assert_eq!(exit_code(&Report::msg("other")), 1);
+}
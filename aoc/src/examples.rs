@@ -0,0 +1,265 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Tests generated from the example files of each day
+//!
+//! Each day has an `examples/` directory with the example inputs and a
+//! `manifest` with one line per test:
+//!
+//! ```text
+//! # file       part  answer  parameters
+//! example.txt  a     41      size=10
+//! example.txt  b     6       size=10
+//! ```
+//!
+//! The day's `build.rs` calls [`build`], which writes the tests of each
+//! part to `OUT_DIR`; the `src/a.rs` and `src/b.rs` modules pull them in
+//! with [`example_tests!`](crate::example_tests). Adding a regression case is
+//! just a matter of dropping the input in `examples/` and adding a line
+//! to the manifest.
+
+use std::collections::{BTreeMap, HashSet};
+use std::env;
+use std::fmt::{Display, Write};
+use std::fs;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::error::{Location, PuzzleError};
+use crate::Result;
+
+/// One line of the manifest
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub line: usize,
+    pub file: String,
+    pub part: String,
+    pub answer: String,
+    pub params: Vec<(String, String)>,
+}
+
+impl Example {
+    /// Name of the generated test, made from the file and the parameters
+    ///
+    /// The `example_` prefix keeps it from clashing with the helpers of
+    /// [`example_tests!`](crate::example_tests).
+    pub fn test_name(&self) -> String {
+        let stem = self.file.rsplit_once('.').map_or(&*self.file, |s| s.0);
+        let mut name = format!("example_{}", stem);
+        for (key, value) in &self.params {
+            write!(name, "_{}_{}", key, value).unwrap();
+        }
+        name.chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>()
+            .to_lowercase()
+    }
+}
+
+/// Parse the manifest; blank lines and `#` comments are skipped
+pub fn parse_manifest(input: &str) -> Result<Vec<Example>> {
+    let mut examples = vec![];
+    for (i, text) in input.lines().enumerate() {
+        let line = i + 1;
+        let text = text.split_once('#').map_or(text, |s| s.0);
+        let mut fields = text.split_whitespace();
+        let Some(file) = fields.next() else {
+            continue;
+        };
+        let location = Location { line, column: 1 };
+        let (Some(part), Some(answer)) = (fields.next(), fields.next()) else {
+            return Err(
+                PuzzleError::invalid_input_at(location, "expected file, part and answer").into(),
+            );
+        };
+        let params = fields
+            .map(|param| {
+                param
+                    .split_once('=')
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .ok_or_else(|| {
+                        PuzzleError::invalid_input_at(
+                            location,
+                            format!("parameter {:?} is not key=value", param),
+                        )
+                    })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        examples.push(Example {
+            line,
+            file: file.to_string(),
+            part: part.to_string(),
+            answer: answer.to_string(),
+            params,
+        });
+    }
+    Ok(examples)
+}
+
+/// Parameters of an example, like the grid size
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, String>);
+
+impl Params {
+    pub fn new(params: &[(&str, &str)]) -> Params {
+        Params(
+            params
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        )
+    }
+
+    pub fn get<T>(&self, key: &str) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let value = self.0.get(key).ok_or_else(|| {
+            PuzzleError::invalid_input(format!("example parameter {} missing", key))
+        })?;
+        Ok(value.parse::<T>().map_err(|e| {
+            PuzzleError::invalid_input(format!("example parameter {}={}: {}", key, value, e))
+        })?)
+    }
+}
+
+/// Generate the test source of each part, for `include!`
+///
+/// Parts `a` and `b` are always present, so that the bins can include
+/// them before there is any example.
+pub fn generate(dir: &str, examples: &[Example]) -> Result<BTreeMap<String, String>> {
+    let mut parts = BTreeMap::<String, String>::default();
+    parts.insert("a".to_string(), String::new());
+    parts.insert("b".to_string(), String::new());
+    let mut names = HashSet::<(String, String)>::default();
+    for example in examples {
+        let mut name = example.test_name();
+        if !names.insert((example.part.clone(), name.clone())) {
+            name = format!("{}_line{}", name, example.line);
+        }
+        let path = format!("{}/{}", dir, example.file);
+        let params = example
+            .params
+            .iter()
+            .map(|(k, v)| format!("({:?}, {:?})", k, v))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let code = parts.entry(example.part.clone()).or_default();
+        writeln!(code, "#[test]")?;
+        writeln!(code, "fn {}() -> Result<()> {{", name)?;
+        writeln!(
+            code,
+            "    run(include_str!({:?}), &[{}], {:?})",
+            path, params, example.answer
+        )?;
+        writeln!(code, "}}")?;
+    }
+    Ok(parts)
+}
+
+/// Entry point for the `build.rs` of the days
+///
+/// Reads `examples/manifest`, if there is one, and writes
+/// `examples_<part>.rs` to `OUT_DIR`.
+pub fn build() -> Result<()> {
+    let dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR")?).join("examples");
+    let out = PathBuf::from(env::var("OUT_DIR")?);
+    println!("cargo:rerun-if-changed={}", dir.display());
+    let manifest = dir.join("manifest");
+    let examples = if manifest.exists() {
+        parse_manifest(&fs::read_to_string(&manifest)?)?
+    } else {
+        vec![]
+    };
+    for example in &examples {
+        if !dir.join(&example.file).is_file() {
+            return Err(PuzzleError::invalid_input_at(
+                Location {
+                    line: example.line,
+                    column: 1,
+                },
+                format!("example file {} not found", example.file),
+            )
+            .into());
+        }
+    }
+    for (part, code) in generate(&dir.to_string_lossy(), &examples)? {
+        fs::write(out.join(format!("examples_{}.rs", part)), code)?;
+    }
+    Ok(())
+}
+
+/// Include the tests generated by [`build`] for the given part
+///
+/// The second argument runs the solver, either as the path of a
+/// `process(bufin)` function or as `|bufin, params| ...`, where `params`
+/// is a [`Params`]. The answer is compared with the manifest's as a
+/// string.
+#[macro_export]
+macro_rules! example_tests {
+    ($part:literal, |$bufin:ident, $params:ident| $body:expr) => {
+        #[cfg(test)]
+        mod examples {
+            use super::*;
+            use $crate::Result;
+
+            #[allow(dead_code)]
+            fn run(input: &str, params: &[(&str, &str)], answer: &str) -> Result<()> {
+                let $bufin = input.as_bytes();
+                let $params = $crate::examples::Params::new(params);
+                assert_eq!($body?.to_string(), answer);
+                Ok(())
+            }
+
+            include!(concat!(env!("OUT_DIR"), "/examples_", $part, ".rs"));
+        }
+    };
+    ($part:literal, $process:path) => {
+        $crate::example_tests!($part, |bufin, _params| $process(bufin));
+    };
+}
+
+#[test]
+fn test_manifest() -> Result<()> {
+    let examples = parse_manifest(
+        "# file part answer params\n\
+         \n\
+         example.txt  a 41  size=10  # the one in the puzzle\n\
+         example.txt  b (6,1)\n",
+    )?;
+    assert_eq!(examples.len(), 2);
+    assert_eq!(examples[0].line, 3);
+    assert_eq!(examples[0].answer, "41");
+    assert_eq!(examples[0].params, vec![("size".into(), "10".into())]);
+    assert_eq!(examples[0].test_name(), "example_example_size_10");
+    assert_eq!(examples[1].answer, "(6,1)");
+    assert_eq!(examples[1].test_name(), "example_example");
+    let run = parse_manifest("run.txt a 1\n")?;
+    assert_eq!(run[0].test_name(), "example_run");
+    assert!(parse_manifest("example.txt a\n").is_err());
+    assert!(parse_manifest("example.txt a 1 size\n").is_err());
+    Ok(())
+}
+
+#[test]
+fn test_generate() -> Result<()> {
+    let examples = parse_manifest("2.txt b 7\n2.txt b 8\n")?;
+    let parts = generate("/x", &examples)?;
+    assert_eq!(parts["a"], "");
+    assert!(parts["b"].contains("fn example_2() -> Result<()> {"));
+    assert!(parts["b"].contains("fn example_2_line2() -> Result<()> {"));
+    assert!(parts["b"].contains("run(include_str!(\"/x/2.txt\"), &[], \"8\")"));
+    Ok(())
+}
+
+#[test]
+fn test_params() -> Result<()> {
+    let params = Params::new(&[("size", "10")]);
+    assert_eq!(params.get::<u16>("size")?, 10);
+    assert!(params.get::<u16>("width").is_err());
+    assert!(params.get::<u8>("size").is_ok());
+    assert!(Params::new(&[("size", "x")]).get::<u16>("size").is_err());
+    Ok(())
+}
@@ -0,0 +1,205 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Synthetic puzzle inputs
+//!
+//! The days that have a [`Generator`] can produce random valid inputs of
+//! any size, which is how we find the scaling bugs that neither the tiny
+//! examples nor our single real input exercise. [`check`] compares the
+//! optimized solver with a slow reference one on many small instances.
+//!
+//! Everything is derived from a seed with our own [`Rng`], so that a
+//! failure can be replayed with `AOC_SEED=<seed> cargo test`, and that
+//! `aoc generate` gives the same input for the same seed across
+//! versions.
+
+use std::env;
+use std::fmt::Debug;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::Result;
+
+/// SplitMix64 pseudo-random number generator
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`; panics if `n` is zero
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// Uniform in `lo..=hi`
+    pub fn between(&mut self, lo: u64, hi: u64) -> u64 {
+        assert!(lo <= hi, "empty range");
+        match (hi - lo).checked_add(1) {
+            Some(n) => lo + self.below(n),
+            None => self.next_u64(),
+        }
+    }
+
+    /// True with probability `num / den`
+    pub fn chance(&mut self, num: u64, den: u64) -> bool {
+        self.below(den) < num
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    /// Fisher-Yates shuffle
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+/// Producer of random valid inputs for a day
+pub trait Generator {
+    /// Input with about `size` elements - lines, stones, blocks, etc.
+    fn generate(&self, rng: &mut Rng, size: usize) -> String;
+
+    /// Input for the given seed
+    fn generate_seeded(&self, seed: u64, size: usize) -> String {
+        self.generate(&mut Rng::new(seed), size)
+    }
+}
+
+/// Seeds for the checks: `0..count`, or just the one in `AOC_SEED`
+pub fn seeds(count: u64) -> Vec<u64> {
+    match env::var("AOC_SEED").ok().and_then(|s| s.parse().ok()) {
+        Some(seed) => vec![seed],
+        None => (0..count).collect(),
+    }
+}
+
+/// Compare `optimized` with `reference` on `count` generated inputs
+///
+/// Panics with the seed and the input of the first disagreement, which
+/// includes the optimized solver panicking.
+pub fn check<G, T, F, R>(generator: &G, size: usize, count: u64, optimized: F, reference: R)
+where
+    G: Generator + ?Sized,
+    T: PartialEq + Debug,
+    F: Fn(&str) -> Result<T>,
+    R: Fn(&str) -> Result<T>,
+{
+    for seed in seeds(count) {
+        let input = generator.generate_seeded(seed, size);
+        let expected = match reference(&input) {
+            Ok(expected) => expected,
+            Err(e) => panic!(
+                "seed {} size {}: reference failed: {}\n{}",
+                seed, size, e, input
+            ),
+        };
+        let got = match panic::catch_unwind(AssertUnwindSafe(|| optimized(&input))) {
+            Ok(got) => got.map_err(|e| e.to_string()),
+            Err(_) => Err("panicked".to_string()),
+        };
+        assert!(
+            got.as_ref() == Ok(&expected),
+            "seed {} size {}: optimized {:?} != reference {:?}; \
+             replay with AOC_SEED={}\n{}",
+            seed,
+            size,
+            got,
+            expected,
+            seed,
+            input
+        );
+    }
+}
+
+/// Tests that [`check`] each solver against its reference on `count`
+/// generated inputs of `size`
+///
+/// A part's `process(bufin)` is given as `a => reference_a`, any other
+/// solver as `name: |input| ... => reference`.
+///
+/// ```ignore
+/// generator_tests!(Gen, 20, 100, a => reference_a, b => reference_b);
+/// ```
+#[macro_export]
+macro_rules! generator_tests {
+    ($gen:expr, $size:expr, $count:expr, $($part:ident => $reference:expr),+ $(,)?) => {
+        $crate::generator_tests!(
+            $gen,
+            $size,
+            $count,
+            $($part: |input: &str| $part::process(input.as_bytes()) => $reference),+
+        );
+    };
+    ($gen:expr, $size:expr, $count:expr, $($name:ident: $optimized:expr => $reference:expr),+ $(,)?) => {
+        #[cfg(test)]
+        mod generator_tests {
+            use super::*;
+
+            $(
+                #[test]
+                fn $name() {
+                    $crate::generator::check(&$gen, $size, $count, $optimized, $reference);
+                }
+            )+
+        }
+    };
+}
+
+#[test]
+fn test_rng() {
+    let mut rng = Rng::new(7);
+    let first = (0..8).map(|_| rng.next_u64()).collect::<Vec<_>>();
+    let mut rng = Rng::new(7);
+    assert_eq!((0..8).map(|_| rng.next_u64()).collect::<Vec<_>>(), first);
+    assert_ne!(Rng::new(8).next_u64(), first[0]);
+    for _ in 0..1000 {
+        assert!(rng.below(3) < 3);
+        assert!((5..=9).contains(&rng.between(5, 9)));
+    }
+    rng.between(0, u64::MAX);
+    let mut items = (0..10).collect::<Vec<_>>();
+    rng.shuffle(&mut items);
+    items.sort();
+    assert_eq!(items, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_check() {
+    struct Lines;
+    impl Generator for Lines {
+        fn generate(&self, rng: &mut Rng, size: usize) -> String {
+            (0..size).map(|_| format!("{}\n", rng.below(10))).collect()
+        }
+    }
+    check(
+        &Lines,
+        5,
+        10,
+        |input| Ok(input.lines().count()),
+        |input| Ok(input.matches('\n').count()),
+    );
+    let result = panic::catch_unwind(|| {
+        check(&Lines, 5, 10, |_| Ok(1), |_| Ok(2));
+    });
+    assert!(result.is_err());
+    let result = panic::catch_unwind(|| {
+        check(&Lines, 5, 10, |_| -> Result<u32> { panic!() }, |_| Ok(2));
+    });
+    assert!(result.is_err());
+}
@@ -0,0 +1,258 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! 2D vectors and points
+//!
+//! [`V2`] is an unbounded alternative to the `sqrid` positions, for
+//! puzzles where coordinates can go negative or wrap around. It uses the
+//! same screen orientation as `sqrid`: x grows to the east and y grows to
+//! the south, so [`Dir::N`] is `(0, -1)`.
+
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use serde::{Deserialize, Serialize};
+use sqrid::Dir;
+
+use crate::math::Int;
+
+/// 2D vector, or point, with coordinates of type T
+///
+/// Ordered by x and then y.
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct V2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> V2<T> {
+    pub const fn new(x: T, y: T) -> V2<T> {
+        V2 { x, y }
+    }
+}
+
+impl<T: Int> V2<T> {
+    pub const ZERO: V2<T> = V2::new(T::ZERO, T::ZERO);
+
+    /// Multiply both coordinates by `k`
+    pub fn scale(self, k: T) -> V2<T> {
+        V2::new(self.x * k, self.y * k)
+    }
+
+    /// Taxicab distance
+    pub fn manhattan(&self, other: &V2<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Chessboard distance, where diagonal steps count as one
+    pub fn chebyshev(&self, other: &V2<T>) -> T {
+        abs_diff(self.x, other.x).max(abs_diff(self.y, other.y))
+    }
+
+    /// Whether the point is in the `size.x` by `size.y` rectangle at the
+    /// origin
+    pub fn inside(&self, size: V2<T>) -> bool {
+        self.x >= T::ZERO && self.x < size.x && self.y >= T::ZERO && self.y < size.y
+    }
+
+    /// Whether the point is in the rectangle with the given inclusive
+    /// corners
+    pub fn inside_rect(&self, topleft: V2<T>, botright: V2<T>) -> bool {
+        (topleft.x..=botright.x).contains(&self.x) && (topleft.y..=botright.y).contains(&self.y)
+    }
+
+    /// Bring the point into the `size.x` by `size.y` rectangle at the
+    /// origin, as if it were a torus
+    pub fn wrap(self, size: V2<T>) -> V2<T> {
+        V2::new(rem_euclid(self.x, size.x), rem_euclid(self.y, size.y))
+    }
+}
+
+impl<T: Int + Neg<Output = T>> V2<T> {
+    /// Rotate around the origin by the angle of `dir`, taking [`Dir::N`]
+    /// as zero, like `Dir + Dir` does: [`Dir::E`] turns right, [`Dir::W`]
+    /// turns left
+    ///
+    /// Panics on diagonals, as they don't keep integer coordinates.
+    pub fn rotate(self, dir: Dir) -> V2<T> {
+        match dir {
+            Dir::N => self,
+            Dir::E => V2::new(-self.y, self.x),
+            Dir::S => -self,
+            Dir::W => V2::new(self.y, -self.x),
+            _ => panic!("can't rotate {} by diagonal {}", self, dir),
+        }
+    }
+}
+
+fn abs_diff<T: Int>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+fn rem_euclid<T: Int>(a: T, m: T) -> T {
+    let r = a % m;
+    if r < T::ZERO {
+        r + m
+    } else {
+        r
+    }
+}
+
+impl<T: Display> Display for V2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({},{})", self.x, self.y)
+    }
+}
+
+impl<T> From<(T, T)> for V2<T> {
+    fn from((x, y): (T, T)) -> V2<T> {
+        V2::new(x, y)
+    }
+}
+
+impl<T> From<V2<T>> for (T, T) {
+    fn from(v: V2<T>) -> (T, T) {
+        (v.x, v.y)
+    }
+}
+
+/// Unit vector that points to `dir`
+impl<T: Int + Neg<Output = T>> From<Dir> for V2<T> {
+    fn from(dir: Dir) -> V2<T> {
+        let (one, zero) = (T::ONE, T::ZERO);
+        match dir {
+            Dir::N => V2::new(zero, -one),
+            Dir::NE => V2::new(one, -one),
+            Dir::E => V2::new(one, zero),
+            Dir::SE => V2::new(one, one),
+            Dir::S => V2::new(zero, one),
+            Dir::SW => V2::new(-one, one),
+            Dir::W => V2::new(-one, zero),
+            Dir::NW => V2::new(-one, -one),
+        }
+    }
+}
+
+impl<T: From<u16>, const XMAX: u16, const YMAX: u16> From<sqrid::Pos<XMAX, YMAX>> for V2<T> {
+    fn from(pos: sqrid::Pos<XMAX, YMAX>) -> V2<T> {
+        let (x, y): (u16, u16) = pos.into();
+        V2::new(x.into(), y.into())
+    }
+}
+
+impl<T: Int, const XMAX: u16, const YMAX: u16> TryFrom<V2<T>> for sqrid::Pos<XMAX, YMAX> {
+    type Error = sqrid::Error;
+    fn try_from(v: V2<T>) -> Result<Self, Self::Error> {
+        let x = u16::try_from(v.x.to_i128()).map_err(|_| sqrid::Error::OutOfBounds)?;
+        let y = u16::try_from(v.y.to_i128()).map_err(|_| sqrid::Error::OutOfBounds)?;
+        sqrid::Pos::new(x, y)
+    }
+}
+
+impl<T: Add<Output = T>> Add for V2<T> {
+    type Output = V2<T>;
+    fn add(self, other: V2<T>) -> V2<T> {
+        V2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for V2<T> {
+    type Output = V2<T>;
+    fn sub(self, other: V2<T>) -> V2<T> {
+        V2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for V2<T> {
+    type Output = V2<T>;
+    fn neg(self) -> V2<T> {
+        V2::new(-self.x, -self.y)
+    }
+}
+
+impl<T: Int> Mul<T> for V2<T> {
+    type Output = V2<T>;
+    fn mul(self, k: T) -> V2<T> {
+        self.scale(k)
+    }
+}
+
+impl<T: Add<Output = T> + Copy> AddAssign for V2<T> {
+    fn add_assign(&mut self, other: V2<T>) {
+        *self = *self + other;
+    }
+}
+
+impl<T: Sub<Output = T> + Copy> SubAssign for V2<T> {
+    fn sub_assign(&mut self, other: V2<T>) {
+        *self = *self - other;
+    }
+}
+
+/// One step towards `dir`
+impl<T: Int + Neg<Output = T>> Add<Dir> for V2<T> {
+    type Output = V2<T>;
+    fn add(self, dir: Dir) -> V2<T> {
+        self + V2::from(dir)
+    }
+}
+
+#[test]
+fn test_ops() {
+    let a = V2::new(3_i32, -2);
+    let b = V2::new(-1, 5);
+    assert_eq!(a + b, V2::new(2, 3));
+    assert_eq!(a - b, V2::new(4, -7));
+    assert_eq!(a * 3, V2::new(9, -6));
+    assert_eq!(-a, V2::new(-3, 2));
+    assert_eq!(a.manhattan(&b), 11);
+    assert_eq!(a.chebyshev(&b), 7);
+    assert_eq!(V2::new(7_u64, 2).manhattan(&V2::new(2, 9)), 12);
+    assert_eq!(a + Dir::N, V2::new(3, -3));
+    assert_eq!(a + Dir::SE, V2::new(4, -1));
+    assert!(V2::new(1_u64, 2) < V2::new(2, 0));
+}
+
+#[test]
+fn test_rotate() {
+    let v = V2::<i64>::from(Dir::N);
+    assert_eq!(v.rotate(Dir::E), V2::from(Dir::E));
+    assert_eq!(v.rotate(Dir::S), V2::from(Dir::S));
+    assert_eq!(v.rotate(Dir::W), V2::from(Dir::W));
+    for d in Dir::iter::<false>() {
+        for turn in Dir::iter::<false>() {
+            assert_eq!(V2::<i32>::from(d).rotate(turn), V2::from(d + turn));
+        }
+    }
+}
+
+#[test]
+fn test_bounds() {
+    let size = V2::new(11, 7);
+    assert!(V2::new(0, 0).inside(size));
+    assert!(V2::new(10, 6).inside(size));
+    assert!(!V2::new(11, 6).inside(size));
+    assert!(!V2::new(0, -1).inside(size));
+    assert!(V2::new(2, 3).inside_rect(V2::new(2, 1), V2::new(4, 3)));
+    assert!(!V2::new(5, 3).inside_rect(V2::new(2, 1), V2::new(4, 3)));
+    assert_eq!(V2::new(-1, 15).wrap(size), V2::new(10, 1));
+}
+
+#[test]
+fn test_sqrid() -> Result<(), sqrid::Error> {
+    type Pos = sqrid::Pos<9, 9>;
+    let pos = Pos::new(3_u16, 4_u16)?;
+    let v = V2::<i32>::from(pos);
+    assert_eq!(v, V2::new(3, 4));
+    assert_eq!(Pos::try_from(v)?, pos);
+    assert!(Pos::try_from(V2::new(-1, 4)).is_err());
+    assert!(Pos::try_from(V2::new(3, 10)).is_err());
+    Ok(())
+}
@@ -0,0 +1,448 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Graphs over any hashable node type
+//!
+//! [`Graph`] is directed; undirected graphs are built with
+//! [`Graph::add_undirected`], which adds both directions. Nodes are kept
+//! in insertion order, and every algorithm here visits them in that
+//! order, so that results are deterministic.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, HashMap};
+use std::fmt::Display;
+use std::hash::Hash;
+
+use color_eyre::Result;
+
+use crate::error::PuzzleError;
+
+#[derive(Debug, Clone)]
+pub struct Graph<N> {
+    nodes: Vec<N>,
+    index: HashMap<N, usize>,
+    succ: Vec<BTreeSet<usize>>,
+    pred: Vec<BTreeSet<usize>>,
+}
+
+impl<N> Default for Graph<N> {
+    fn default() -> Self {
+        Graph {
+            nodes: vec![],
+            index: HashMap::default(),
+            succ: vec![],
+            pred: vec![],
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Graph with a directed edge for each `(from, to)` pair
+    pub fn from_edges(edges: impl IntoIterator<Item = (N, N)>) -> Self {
+        let mut g = Self::new();
+        for (from, to) in edges {
+            g.add_edge(from, to);
+        }
+        g
+    }
+
+    /// Graph with an undirected edge for each pair
+    pub fn from_undirected(edges: impl IntoIterator<Item = (N, N)>) -> Self {
+        let mut g = Self::new();
+        for (a, b) in edges {
+            g.add_undirected(a, b);
+        }
+        g
+    }
+
+    fn node_index(&mut self, node: N) -> usize {
+        if let Some(&i) = self.index.get(&node) {
+            return i;
+        }
+        let i = self.nodes.len();
+        self.index.insert(node.clone(), i);
+        self.nodes.push(node);
+        self.succ.push(BTreeSet::default());
+        self.pred.push(BTreeSet::default());
+        i
+    }
+
+    /// Add a node without edges; does nothing if it's already there
+    pub fn add_node(&mut self, node: N) {
+        self.node_index(node);
+    }
+
+    pub fn add_edge(&mut self, from: N, to: N) {
+        let from = self.node_index(from);
+        let to = self.node_index(to);
+        self.succ[from].insert(to);
+        self.pred[to].insert(from);
+    }
+
+    pub fn add_undirected(&mut self, a: N, b: N) {
+        self.add_edge(a.clone(), b.clone());
+        self.add_edge(b, a);
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.index.contains_key(node)
+    }
+
+    pub fn has_edge(&self, from: &N, to: &N) -> bool {
+        match (self.index.get(from), self.index.get(to)) {
+            (Some(&from), Some(&to)) => self.succ[from].contains(&to),
+            _ => false,
+        }
+    }
+
+    /// All nodes, in insertion order
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// Nodes reachable from `node` through a single edge
+    pub fn successors(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.index
+            .get(node)
+            .into_iter()
+            .flat_map(|&i| self.succ[i].iter().map(|&j| &self.nodes[j]))
+    }
+
+    /// Nodes that reach `node` through a single edge
+    pub fn predecessors(&self, node: &N) -> impl Iterator<Item = &N> {
+        self.index
+            .get(node)
+            .into_iter()
+            .flat_map(|&i| self.pred[i].iter().map(|&j| &self.nodes[j]))
+    }
+
+    fn to_nodes(&self, indexes: impl IntoIterator<Item = usize>) -> Vec<N> {
+        indexes.into_iter().map(|i| self.nodes[i].clone()).collect()
+    }
+
+    /// Adjacency ignoring edge direction
+    fn undirected(&self) -> Vec<BTreeSet<usize>> {
+        self.succ
+            .iter()
+            .zip(&self.pred)
+            .map(|(s, p)| s.union(p).copied().collect())
+            .collect()
+    }
+
+    /// Some cycle of the graph, as a list of nodes where each one has an
+    /// edge to the next and the last one has an edge to the first
+    pub fn find_cycle(&self) -> Option<Vec<N>> {
+        // 0: unvisited, 1: in the current path, 2: done
+        let mut state = vec![0_u8; self.len()];
+        for start in 0..self.len() {
+            if state[start] != 0 {
+                continue;
+            }
+            let mut path = vec![start];
+            let mut iters = vec![self.succ[start].iter()];
+            state[start] = 1;
+            while let Some(it) = iters.last_mut() {
+                match it.next() {
+                    Some(&next) if state[next] == 1 => {
+                        let pos = path.iter().position(|&i| i == next).unwrap();
+                        return Some(self.to_nodes(path[pos..].iter().copied()));
+                    }
+                    Some(&next) if state[next] == 0 => {
+                        state[next] = 1;
+                        path.push(next);
+                        iters.push(self.succ[next].iter());
+                    }
+                    Some(_) => {}
+                    None => {
+                        state[path.pop().unwrap()] = 2;
+                        iters.pop();
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Order in which every edge goes forward
+    ///
+    /// Among the nodes that are ready, the ones inserted first come first.
+    /// A graph with a cycle has no such order, and yields a
+    /// PuzzleError::Cycle that lists one of them.
+    pub fn toposort(&self) -> Result<Vec<N>>
+    where
+        N: Display,
+    {
+        let mut indegree = self.pred.iter().map(|p| p.len()).collect::<Vec<_>>();
+        let mut ready = (0..self.len())
+            .filter(|&i| indegree[i] == 0)
+            .map(Reverse)
+            .collect::<BinaryHeap<_>>();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(Reverse(i)) = ready.pop() {
+            order.push(i);
+            for &j in &self.succ[i] {
+                indegree[j] -= 1;
+                if indegree[j] == 0 {
+                    ready.push(Reverse(j));
+                }
+            }
+        }
+        if order.len() < self.len() {
+            let cycle = self
+                .find_cycle()
+                .expect("nodes left out must be in a cycle");
+            let mut desc = cycle
+                .iter()
+                .map(|n| n.to_string())
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            desc.push_str(&format!(" -> {}", cycle[0]));
+            return Err(PuzzleError::cycle(desc).into());
+        }
+        Ok(self.to_nodes(order))
+    }
+
+    /// Strongly connected components, with Tarjan's algorithm
+    ///
+    /// Components come out in reverse topological order: no edge goes
+    /// from a component to one that comes after it.
+    pub fn scc(&self) -> Vec<Vec<N>> {
+        const UNSEEN: usize = usize::MAX;
+        let mut index = vec![UNSEEN; self.len()];
+        let mut lowlink = vec![0; self.len()];
+        let mut on_stack = vec![false; self.len()];
+        let mut stack = vec![];
+        let mut next_index = 0;
+        let mut components = vec![];
+        for root in 0..self.len() {
+            if index[root] != UNSEEN {
+                continue;
+            }
+            // Explicit call stack of (node, successors left to visit)
+            let mut calls = vec![(root, self.succ[root].iter())];
+            index[root] = next_index;
+            lowlink[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            while let Some((v, it)) = calls.last_mut() {
+                let v = *v;
+                if let Some(&w) = it.next() {
+                    if index[w] == UNSEEN {
+                        index[w] = next_index;
+                        lowlink[w] = next_index;
+                        next_index += 1;
+                        stack.push(w);
+                        on_stack[w] = true;
+                        calls.push((w, self.succ[w].iter()));
+                    } else if on_stack[w] {
+                        lowlink[v] = lowlink[v].min(index[w]);
+                    }
+                    continue;
+                }
+                calls.pop();
+                if let Some((parent, _)) = calls.last() {
+                    lowlink[*parent] = lowlink[*parent].min(lowlink[v]);
+                }
+                if lowlink[v] == index[v] {
+                    let mut component = vec![];
+                    loop {
+                        let w = stack.pop().unwrap();
+                        on_stack[w] = false;
+                        component.push(w);
+                        if w == v {
+                            break;
+                        }
+                    }
+                    component.sort();
+                    components.push(self.to_nodes(component));
+                }
+            }
+        }
+        components
+    }
+
+    /// Connected components, ignoring edge direction
+    pub fn components(&self) -> Vec<Vec<N>> {
+        let adj = self.undirected();
+        let mut seen = vec![false; self.len()];
+        let mut components = vec![];
+        for root in 0..self.len() {
+            if seen[root] {
+                continue;
+            }
+            seen[root] = true;
+            let mut component = vec![];
+            let mut pending = vec![root];
+            while let Some(i) = pending.pop() {
+                component.push(i);
+                for &j in &adj[i] {
+                    if !seen[j] {
+                        seen[j] = true;
+                        pending.push(j);
+                    }
+                }
+            }
+            component.sort();
+            components.push(self.to_nodes(component));
+        }
+        components
+    }
+
+    /// All sets of 3 nodes that are adjacent to each other, ignoring edge
+    /// direction
+    pub fn triangles(&self) -> Vec<[N; 3]> {
+        let adj = self.undirected();
+        let mut triangles = vec![];
+        for a in 0..self.len() {
+            for &b in adj[a].range(a + 1..) {
+                for &c in adj[b].range(b + 1..) {
+                    if adj[a].contains(&c) {
+                        let [a, b, c] = [a, b, c].map(|i| self.nodes[i].clone());
+                        triangles.push([a, b, c]);
+                    }
+                }
+            }
+        }
+        triangles
+    }
+
+    /// All cliques that can't be extended with another node, ignoring
+    /// edge direction, with Bron-Kerbosch
+    pub fn maximal_cliques(&self) -> Vec<Vec<N>> {
+        let mut adj = self.undirected();
+        // Self-loops don't make cliques larger, and would hide the node
+        // from the pivot's non-neighbors
+        for (u, neighbors) in adj.iter_mut().enumerate() {
+            neighbors.remove(&u);
+        }
+        let mut cliques = vec![];
+        let candidates = (0..self.len()).collect::<BTreeSet<_>>();
+        bron_kerbosch(
+            &adj,
+            &mut vec![],
+            candidates,
+            BTreeSet::default(),
+            &mut cliques,
+        );
+        cliques
+            .into_iter()
+            .map(|mut clique| {
+                clique.sort();
+                self.to_nodes(clique)
+            })
+            .collect()
+    }
+}
+
+fn bron_kerbosch(
+    adj: &[BTreeSet<usize>],
+    clique: &mut Vec<usize>,
+    mut candidates: BTreeSet<usize>,
+    mut excluded: BTreeSet<usize>,
+    cliques: &mut Vec<Vec<usize>>,
+) {
+    let Some(&pivot) = candidates
+        .union(&excluded)
+        .max_by_key(|&&u| adj[u].intersection(&candidates).count())
+    else {
+        cliques.push(clique.clone());
+        return;
+    };
+    let others = candidates
+        .difference(&adj[pivot])
+        .copied()
+        .collect::<Vec<_>>();
+    for v in others {
+        let neighbors =
+            |set: &BTreeSet<usize>| set.intersection(&adj[v]).copied().collect::<BTreeSet<_>>();
+        clique.push(v);
+        bron_kerbosch(
+            adj,
+            clique,
+            neighbors(&candidates),
+            neighbors(&excluded),
+            cliques,
+        );
+        clique.pop();
+        candidates.remove(&v);
+        excluded.insert(v);
+    }
+}
+
+#[test]
+fn test_toposort() -> Result<()> {
+    let g = Graph::from_edges([(3, 1), (1, 2), (3, 2), (4, 2)]);
+    assert_eq!(g.toposort()?, vec![3, 1, 4, 2]);
+    assert!(g.has_edge(&3, &1));
+    assert!(!g.has_edge(&1, &3));
+    assert_eq!(g.successors(&3).copied().collect::<Vec<_>>(), vec![1, 2]);
+    assert_eq!(g.predecessors(&2).count(), 3);
+    assert_eq!(g.find_cycle(), None);
+    let g = Graph::from_edges([("a", "b"), ("b", "c"), ("c", "d"), ("d", "b")]);
+    assert_eq!(g.find_cycle(), Some(vec!["b", "c", "d"]));
+    let err = g.toposort().unwrap_err();
+    assert_eq!(crate::error::exit_code(&err), 4);
+    assert_eq!(err.to_string(), "cycle detected: b -> c -> d -> b");
+    Ok(())
+}
+
+#[test]
+fn test_scc() {
+    let g = Graph::from_edges([(1, 2), (2, 3), (3, 1), (3, 4), (4, 5), (5, 4), (6, 6)]);
+    assert_eq!(g.scc(), vec![vec![4, 5], vec![1, 2, 3], vec![6]]);
+}
+
+#[test]
+fn test_components() {
+    let mut g = Graph::from_edges([(1, 2), (3, 2), (4, 5)]);
+    g.add_node(6);
+    assert_eq!(g.len(), 6);
+    assert_eq!(g.components(), vec![vec![1, 2, 3], vec![4, 5], vec![6]]);
+}
+
+#[test]
+fn test_cliques() {
+    // Two squares sharing an edge, with the diagonals of the first one
+    let g = Graph::from_undirected([
+        ('a', 'b'),
+        ('b', 'c'),
+        ('c', 'd'),
+        ('d', 'a'),
+        ('a', 'c'),
+        ('b', 'd'),
+        ('c', 'e'),
+        ('e', 'f'),
+        ('f', 'd'),
+    ]);
+    assert_eq!(g.triangles().len(), 4);
+    let mut cliques = g.maximal_cliques();
+    cliques.sort();
+    assert_eq!(
+        cliques,
+        vec![
+            vec!['a', 'b', 'c', 'd'],
+            vec!['c', 'e'],
+            vec!['d', 'f'],
+            vec!['e', 'f'],
+        ]
+    );
+    // A lone node with a self-loop is a clique by itself
+    let g = Graph::from_undirected([('a', 'a'), ('b', 'c'), ('c', 'c')]);
+    let mut cliques = g.maximal_cliques();
+    cliques.sort();
+    assert_eq!(cliques, vec![vec!['a'], vec!['b', 'c']]);
+}
@@ -13,6 +13,50 @@ pub use color_eyre::Result;
 #[macro_use]
 pub mod parser;
 
+pub mod error;
+pub use error::*;
+
+pub mod math;
+
+pub mod linalg;
+
+pub mod geom;
+pub use geom::V2;
+
+pub mod graph;
+
+pub mod dsu;
+
+pub mod cycle;
+
+pub mod pq;
+pub use pq::PriorityQueue;
+
+pub mod collections;
+pub use collections::{FastHashMap, FastHashSet, PosDirMap, PosDirSet, PosMap, PosSet};
+
+pub mod examples;
+
+pub mod generator;
+
+pub mod validate;
+
+pub mod dump;
+
+pub mod minimize;
+
+pub mod svg;
+
+pub mod checkpoint;
+
+pub mod anonymize;
+
+pub mod solution;
+pub use solution::{Both, Phases, Solution};
+
+#[cfg(feature = "tui")]
+pub mod tui;
+
 /*****************************************************************************/
 
 /// Extend Option with ok_or_eyre
@@ -36,32 +80,6 @@ impl<T> OptionExt<T> for Option<T> {
 
 /*****************************************************************************/
 
-/// Wrapper that adds Eq and Ord using Debug
-#[derive(Debug)]
-pub struct OrdWrapper<T>(pub T);
-
-impl<T: Debug> std::cmp::PartialEq for OrdWrapper<T> {
-    fn eq(&self, other: &Self) -> bool {
-        format!("{:?}", self).eq(&format!("{:?}", other))
-    }
-}
-
-impl<T: Debug> Eq for OrdWrapper<T> {}
-
-impl<T: Debug> std::cmp::PartialOrd for OrdWrapper<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl<T: Debug> std::cmp::Ord for OrdWrapper<T> {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        format!("{:?}", self).cmp(&format!("{:?}", other))
-    }
-}
-
-/*****************************************************************************/
-
 // main function
 
 pub fn elapsed(start: &Instant) -> String {
@@ -71,7 +89,15 @@ pub fn elapsed(start: &Instant) -> String {
 pub fn do_main<F: Fn() -> Result<T>, T: Display>(f: F) -> Result<()> {
     color_eyre::install()?;
     let start = Instant::now();
-    println!("{}", f()?);
-    println!("Elapsed: {}", elapsed(&start));
-    Ok(())
+    match f() {
+        Ok(answer) => {
+            println!("{}", answer);
+            println!("Elapsed: {}", elapsed(&start));
+            Ok(())
+        }
+        Err(report) => {
+            eprintln!("Error: {:?}", report);
+            std::process::exit(exit_code(&report));
+        }
+    }
 }
@@ -0,0 +1,331 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Exact linear algebra over i128-backed rationals
+//!
+//! Small systems of linear equations come up often in puzzles, and they
+//! usually need an exact, and often an integer, answer. Floating point is
+//! not an option, so we do Gaussian elimination over [`Rational`].
+
+use std::fmt::{self, Display};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use color_eyre::Result;
+
+use crate::math::{self, overflow_check, Int};
+
+/// Rational number, always stored in lowest terms with a positive
+/// denominator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    num: i128,
+    den: i128,
+}
+
+impl Rational {
+    pub const ZERO: Rational = Rational { num: 0, den: 1 };
+    pub const ONE: Rational = Rational { num: 1, den: 1 };
+
+    /// Panics if `den` is zero
+    pub fn new(num: i128, den: i128) -> Rational {
+        Self::checked_new(num, den).expect("invalid rational")
+    }
+
+    /// None if `den` is zero or the normalized value doesn't fit
+    pub fn checked_new(num: i128, den: i128) -> Option<Rational> {
+        if den == 0 {
+            return None;
+        }
+        let g = math::checked_gcd(num, den)?;
+        let g = if den < 0 { g.checked_neg()? } else { g };
+        Some(Rational {
+            num: num / g,
+            den: den / g,
+        })
+    }
+
+    pub fn from_int<T: Int>(n: T) -> Rational {
+        Rational {
+            num: n.to_i128(),
+            den: 1,
+        }
+    }
+
+    pub fn numer(&self) -> i128 {
+        self.num
+    }
+
+    pub fn denom(&self) -> i128 {
+        self.den
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.num == 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        self.den == 1
+    }
+
+    /// The value as an integer of type T, if it is one and fits
+    pub fn to_integer<T: Int>(&self) -> Option<T> {
+        self.is_integer().then(|| T::from_i128(self.num)).flatten()
+    }
+
+    pub fn checked_add(self, other: Rational) -> Option<Rational> {
+        let g = math::gcd(self.den, other.den);
+        let den = (self.den / g).checked_mul(other.den)?;
+        let num = self
+            .num
+            .checked_mul(other.den / g)?
+            .checked_add(other.num.checked_mul(self.den / g)?)?;
+        Rational::checked_new(num, den)
+    }
+
+    pub fn checked_sub(self, other: Rational) -> Option<Rational> {
+        self.checked_add(other.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, other: Rational) -> Option<Rational> {
+        // Cross-reduce first to keep the intermediate values small; the
+        // denominators are positive, so neither gcd is zero
+        debug_assert!(self.den > 0 && other.den > 0);
+        let g1 = math::gcd(self.num, other.den);
+        let g2 = math::gcd(other.num, self.den);
+        let num = (self.num / g1).checked_mul(other.num / g2)?;
+        let den = (self.den / g2).checked_mul(other.den / g1)?;
+        Rational::checked_new(num, den)
+    }
+
+    pub fn checked_div(self, other: Rational) -> Option<Rational> {
+        self.checked_mul(other.checked_recip()?)
+    }
+
+    pub fn checked_neg(self) -> Option<Rational> {
+        Some(Rational {
+            num: self.num.checked_neg()?,
+            den: self.den,
+        })
+    }
+
+    /// None for zero
+    pub fn checked_recip(self) -> Option<Rational> {
+        Rational::checked_new(self.den, self.num)
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::ZERO
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.den == 1 {
+            write!(f, "{}", self.num)
+        } else {
+            write!(f, "{}/{}", self.num, self.den)
+        }
+    }
+}
+
+macro_rules! rational_op {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        impl $trait for Rational {
+            type Output = Rational;
+            fn $method(self, other: Rational) -> Rational {
+                self.$checked(other)
+                    .unwrap_or_else(|| panic!("{} {} {} failed", self, stringify!($method), other))
+            }
+        }
+    };
+}
+
+rational_op!(Add, add, checked_add);
+rational_op!(Sub, sub, checked_sub);
+rational_op!(Mul, mul, checked_mul);
+rational_op!(Div, div, checked_div);
+
+impl Neg for Rational {
+    type Output = Rational;
+    fn neg(self) -> Rational {
+        self.checked_neg().expect("rational negation overflow")
+    }
+}
+
+/// Result of solving a linear system
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Solution {
+    /// Exactly one solution
+    Unique(Vec<Rational>),
+    /// Infinitely many solutions: `particular` is the one with all `free`
+    /// variables set to zero
+    Underdetermined {
+        particular: Vec<Rational>,
+        free: Vec<usize>,
+    },
+    /// No solution at all
+    Inconsistent,
+}
+
+impl Solution {
+    /// The unique solution, if it exists and is made of integers of type T
+    pub fn integral<T: Int>(&self) -> Option<Vec<T>> {
+        match self {
+            Solution::Unique(values) => values.iter().map(|v| v.to_integer()).collect(),
+            _ => None,
+        }
+    }
+}
+
+fn to_rationals<T: Int>(a: &[Vec<T>], b: &[T]) -> Vec<Vec<Rational>> {
+    assert_eq!(a.len(), b.len(), "matrix and vector sizes differ");
+    a.iter()
+        .zip(b)
+        .map(|(row, &bi)| {
+            row.iter()
+                .copied()
+                .chain(std::iter::once(bi))
+                .map(Rational::from_int)
+                .collect()
+        })
+        .collect()
+}
+
+/// Bring the augmented matrix to reduced row echelon form, in place
+///
+/// Returns the column of the pivot of each non-zero row.
+fn rref(m: &mut [Vec<Rational>], ncols: usize) -> Result<Vec<usize>> {
+    let mut pivots = vec![];
+    for col in 0..ncols {
+        let row = pivots.len();
+        let Some(p) = (row..m.len()).find(|&r| !m[r][col].is_zero()) else {
+            continue;
+        };
+        m.swap(row, p);
+        let pivot = m[row][col];
+        for v in m[row].iter_mut() {
+            *v = overflow_check(v.checked_div(pivot), "gaussian elimination")?;
+        }
+        for r in 0..m.len() {
+            let factor = m[r][col];
+            if r == row || factor.is_zero() {
+                continue;
+            }
+            for c in col..m[r].len() {
+                let delta = m[row][c].checked_mul(factor);
+                m[r][c] = overflow_check(
+                    delta.and_then(|d| m[r][c].checked_sub(d)),
+                    "gaussian elimination",
+                )?;
+            }
+        }
+        pivots.push(col);
+    }
+    Ok(pivots)
+}
+
+/// Solve `a * x = b` exactly
+///
+/// `a` has one row per equation and one column per variable; all rows
+/// must have the same length. Overflow of the intermediate rationals is
+/// reported as PuzzleError::Overflow.
+pub fn solve<T: Int>(a: &[Vec<T>], b: &[T]) -> Result<Solution> {
+    let nvars = a.first().map_or(0, |row| row.len());
+    assert!(a.iter().all(|row| row.len() == nvars), "ragged matrix");
+    let mut m = to_rationals(a, b);
+    let pivots = rref(&mut m, nvars)?;
+    if m[pivots.len()..].iter().any(|row| !row[nvars].is_zero()) {
+        return Ok(Solution::Inconsistent);
+    }
+    let mut particular = vec![Rational::ZERO; nvars];
+    for (row, &col) in pivots.iter().enumerate() {
+        particular[col] = m[row][nvars];
+    }
+    if pivots.len() == nvars {
+        Ok(Solution::Unique(particular))
+    } else {
+        let free = (0..nvars).filter(|c| !pivots.contains(c)).collect();
+        Ok(Solution::Underdetermined { particular, free })
+    }
+}
+
+/// Rank of the matrix
+pub fn rank<T: Int>(a: &[Vec<T>]) -> Result<usize> {
+    let ncols = a.first().map_or(0, |row| row.len());
+    let mut m = to_rationals(a, &vec![T::ZERO; a.len()]);
+    Ok(rref(&mut m, ncols)?.len())
+}
+
+/// Whether the square matrix has no inverse
+pub fn is_singular<T: Int>(a: &[Vec<T>]) -> Result<bool> {
+    assert!(
+        a.iter().all(|row| row.len() == a.len()),
+        "matrix not square"
+    );
+    Ok(rank(a)? < a.len())
+}
+
+#[test]
+fn test_rational() {
+    let half = Rational::new(1, 2);
+    let third = Rational::new(-2, -6);
+    assert_eq!(third, Rational::new(1, 3));
+    assert_eq!(half + third, Rational::new(5, 6));
+    assert_eq!(half - third, Rational::new(1, 6));
+    assert_eq!(half * third, Rational::new(1, 6));
+    assert_eq!(half / third, Rational::new(3, 2));
+    assert_eq!(Rational::new(4, -2).to_integer::<i64>(), Some(-2));
+    assert_eq!(half.to_integer::<i64>(), None);
+    assert_eq!(Rational::new(-4, 6).to_string(), "-2/3");
+    assert_eq!(Rational::ZERO.checked_recip(), None);
+    assert_eq!(Rational::new(i128::MAX, 1).checked_add(Rational::ONE), None);
+    assert_eq!(Rational::checked_new(i128::MIN, i128::MIN), None);
+    assert_eq!(Rational::checked_new(0, i128::MIN), None);
+    assert_eq!(
+        Rational::checked_new(i128::MIN, 2),
+        Some(Rational::new(i128::MIN / 2, 1))
+    );
+}
+
+#[test]
+fn test_solve_unique() -> Result<()> {
+    // The first machine of day 13
+    let s = solve(&[vec![94_u64, 22], vec![34, 67]], &[8400, 5400])?;
+    assert_eq!(s.integral::<u64>(), Some(vec![80, 40]));
+    let s = solve(&[vec![2_i64, 1], vec![1, 3]], &[1, 1])?;
+    assert_eq!(
+        s,
+        Solution::Unique(vec![Rational::new(2, 5), Rational::new(1, 5)])
+    );
+    assert_eq!(s.integral::<i64>(), None);
+    Ok(())
+}
+
+#[test]
+fn test_solve_degenerate() -> Result<()> {
+    let a = vec![vec![1_i64, 2], vec![2, 4]];
+    assert!(is_singular(&a)?);
+    assert_eq!(rank(&a)?, 1);
+    assert_eq!(solve(&a, &[3, 7])?, Solution::Inconsistent);
+    assert_eq!(
+        solve(&a, &[3, 6])?,
+        Solution::Underdetermined {
+            particular: vec![Rational::from_int(3), Rational::ZERO],
+            free: vec![1],
+        }
+    );
+    // More equations than variables, but consistent
+    let s = solve(&[vec![1_i64], vec![2], vec![3]], &[2, 4, 6])?;
+    assert_eq!(s.integral::<i64>(), Some(vec![2]));
+    Ok(())
+}
+
+#[test]
+fn test_solve_overflow() {
+    let big = i128::MAX / 2;
+    let err = solve(&[vec![big, 3], vec![5, big]], &[1, 1]).unwrap_err();
+    assert_eq!(crate::error::exit_code(&err), 5);
+}
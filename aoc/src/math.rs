@@ -0,0 +1,313 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Number theory helpers
+//!
+//! Everything here is generic over the [`Int`] trait, implemented for the
+//! primitive integers we use in puzzles. Intermediate values are
+//! computed in `i128` and checked, so that the result either fits in
+//! the caller's type or we get an overflow error.
+
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+use color_eyre::Result;
+
+use crate::error::PuzzleError;
+
+/// Primitive integer usable by the functions in this module
+pub trait Int:
+    Copy
+    + Ord
+    + Hash
+    + Debug
+    + Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+    fn to_i128(self) -> i128;
+    fn from_i128(v: i128) -> Option<Self>;
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+    /// Floor of the square root, None for negative numbers
+    fn checked_isqrt(self) -> Option<Self>;
+}
+
+macro_rules! int_impl {
+    ($($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+                fn from_i128(v: i128) -> Option<Self> {
+                    Self::try_from(v).ok()
+                }
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+                fn checked_pow(self, exp: u32) -> Option<Self> {
+                    <$t>::checked_pow(self, exp)
+                }
+                #[allow(unused_comparisons)]
+                fn checked_isqrt(self) -> Option<Self> {
+                    (self >= 0).then(|| self.isqrt())
+                }
+            }
+        )*
+    };
+}
+
+int_impl!(i32, i64, i128, u32, u64, usize);
+
+/// Turn the None of a checked operation into a PuzzleError::Overflow
+pub fn overflow_check<T, M: Display>(value: Option<T>, what: M) -> Result<T> {
+    value.ok_or_else(|| PuzzleError::overflow(what).into())
+}
+
+fn narrow<T: Int>(v: i128) -> Result<T> {
+    overflow_check(T::from_i128(v), format!("{} does not fit", v))
+}
+
+/// Floor of the square root; panics on negative numbers
+pub fn isqrt<T: Int>(n: T) -> T {
+    n.checked_isqrt()
+        .unwrap_or_else(|| panic!("square root of negative number {}", n))
+}
+
+/// Whether `n` is a perfect square
+pub fn is_square<T: Int>(n: T) -> bool {
+    n.checked_isqrt().is_some_and(|r| r * r == n)
+}
+
+/// Greatest common divisor, always non-negative, or None if it doesn't
+/// fit in T
+pub fn checked_gcd<T: Int>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a.to_i128().unsigned_abs(), b.to_i128().unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    // gcd(a, b) <= max(|a|, |b|), so it only fails for gcd(MIN, 0) or
+    // gcd(MIN, MIN) in signed types.
+    i128::try_from(a).ok().and_then(T::from_i128)
+}
+
+/// Greatest common divisor, always non-negative; panics on overflow
+pub fn gcd<T: Int>(a: T, b: T) -> T {
+    checked_gcd(a, b).expect("gcd overflow")
+}
+
+/// Least common multiple, or None if it doesn't fit in T
+pub fn checked_lcm<T: Int>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    let g = checked_gcd(a, b)?.to_i128();
+    let l = (a.to_i128() / g).checked_mul(b.to_i128())?.checked_abs()?;
+    T::from_i128(l)
+}
+
+/// Least common multiple; panics on overflow
+pub fn lcm<T: Int>(a: T, b: T) -> T {
+    checked_lcm(a, b).unwrap_or_else(|| panic!("lcm({}, {}) overflow", a, b))
+}
+
+/// Least common multiple of all numbers, overflow as error
+pub fn lcm_all<T: Int>(nums: impl IntoIterator<Item = T>) -> Result<T> {
+    nums.into_iter().try_fold(T::ONE, |acc, n| {
+        overflow_check(checked_lcm(acc, n), format!("lcm({}, {})", acc, n))
+    })
+}
+
+/// Extended Euclid: returns `(g, x, y)` with `a * x + b * y == g`, or
+/// None on overflow, which only `MIN` in signed types can cause
+///
+/// The Bézout coefficients can be negative even for unsigned inputs, so
+/// they are returned as i128.
+pub fn egcd<T: Int>(a: T, b: T) -> Option<(T, i128, i128)> {
+    let (mut old_r, mut r) = (a.to_i128(), b.to_i128());
+    let (mut old_x, mut x) = (1_i128, 0_i128);
+    let (mut old_y, mut y) = (0_i128, 1_i128);
+    let next = |old: i128, q: i128, new: i128| old.checked_sub(q.checked_mul(new)?);
+    while r != 0 {
+        let q = old_r.checked_div(r)?;
+        (old_r, r) = (r, next(old_r, q, r)?);
+        (old_x, x) = (x, next(old_x, q, x)?);
+        (old_y, y) = (y, next(old_y, q, y)?);
+    }
+    if old_r < 0 {
+        (old_r, old_x, old_y) = (
+            old_r.checked_neg()?,
+            old_x.checked_neg()?,
+            old_y.checked_neg()?,
+        );
+    }
+    Some((T::from_i128(old_r)?, old_x, old_y))
+}
+
+/// Modular inverse of `a` in `[0, m)`, if `a` and `m` are coprime
+pub fn mod_inv<T: Int>(a: T, m: T) -> Option<T> {
+    assert!(m > T::ZERO, "modulus must be positive");
+    let (g, x, _) = egcd(a.to_i128().rem_euclid(m.to_i128()), m.to_i128())?;
+    if g != 1 {
+        return None;
+    }
+    T::from_i128(x.rem_euclid(m.to_i128()))
+}
+
+/// `a * b mod m` in `[0, m)` without overflowing on the product
+pub fn mul_mod<T: Int>(a: T, b: T, m: T) -> T {
+    assert!(m > T::ZERO, "modulus must be positive");
+    let m = m.to_i128();
+    let (mut a, mut b) = (a.to_i128().rem_euclid(m), b.to_i128().rem_euclid(m));
+    let r = if let Some(p) = a.checked_mul(b) {
+        p % m
+    } else {
+        // Double-and-add, only needed for moduli above 2^63
+        let mut r = 0_i128;
+        while b > 0 {
+            if b & 1 == 1 {
+                r = add_mod(r, a, m);
+            }
+            a = add_mod(a, a, m);
+            b >>= 1;
+        }
+        r
+    };
+    T::from_i128(r).expect("value below modulus must fit")
+}
+
+fn add_mod(a: i128, b: i128, m: i128) -> i128 {
+    // a, b in [0, m): a + b can overflow only if m > 2^126
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// `base ^ exp mod m` by repeated squaring
+pub fn pow_mod<T: Int>(base: T, mut exp: u64, m: T) -> T {
+    let mut result = T::from_i128(1 % m.to_i128()).expect("value below modulus must fit");
+    let mut base = mul_mod(base, T::ONE, m);
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// Chinese remainder theorem over `(remainder, modulus)` pairs
+///
+/// Moduli don't have to be coprime. Returns `(r, m)` such that the
+/// solutions are exactly the numbers congruent to `r` modulo `m`, with `r`
+/// in `[0, m)`. Incompatible congruences yield PuzzleError::NoSolution,
+/// and a combined modulus that doesn't fit in T yields
+/// PuzzleError::Overflow.
+pub fn crt<T: Int>(congruences: &[(T, T)]) -> Result<(T, T)> {
+    let (mut r, mut m) = (0_i128, 1_i128);
+    for &(r2, m2) in congruences {
+        let (r2, m2) = (r2.to_i128(), m2.to_i128());
+        if m2 <= 0 {
+            return Err(PuzzleError::invalid_input(format!("non-positive modulus {}", m2)).into());
+        }
+        let r2 = r2.rem_euclid(m2);
+        let (g, p, _) = overflow_check(egcd(m, m2), format!("egcd({}, {})", m, m2))?;
+        let diff = r2 - r;
+        if diff % g != 0 {
+            return Err(PuzzleError::no_solution(format!(
+                "x = {} mod {} and x = {} mod {} are incompatible",
+                r, m, r2, m2
+            ))
+            .into());
+        }
+        // m * k = diff (mod m2), k = diff / g * p (mod m2 / g)
+        let m2g = m2 / g;
+        let k = mul_mod(diff / g, p, m2g);
+        let lcm = overflow_check(m.checked_mul(m2g), format!("lcm({}, {})", m, m2))?;
+        r = add_mod(r, mul_mod(m, k, lcm), lcm);
+        m = lcm;
+    }
+    Ok((narrow(r)?, narrow(m)?))
+}
+
+#[test]
+fn test_gcd_lcm() {
+    assert_eq!(gcd(12_i64, 18), 6);
+    assert_eq!(gcd(-12_i64, 18), 6);
+    assert_eq!(gcd(0_u64, 7), 7);
+    assert_eq!(checked_gcd(i64::MIN, 2), Some(2));
+    assert_eq!(checked_gcd(i128::MIN, 0), None);
+    assert_eq!(lcm(4_u64, 6), 12);
+    assert_eq!(lcm(101_i128, 103), 10403);
+    assert_eq!(checked_lcm(u64::MAX, u64::MAX - 1), None);
+    assert_eq!(lcm_all([2_u64, 3, 4, 5]).unwrap(), 60);
+    assert!(lcm_all([i64::MAX, i64::MAX - 1]).is_err());
+    assert_eq!(checked_lcm(i64::MIN, i64::MIN), None);
+    let err = lcm_all([i64::MIN, i64::MIN]).unwrap_err();
+    assert_eq!(crate::error::exit_code(&err), 5);
+}
+
+#[test]
+fn test_egcd_mod_inv() {
+    let (g, x, y) = egcd(240_u64, 46).unwrap();
+    assert_eq!(g, 2);
+    assert_eq!(240 * x + 46 * y, 2);
+    assert_eq!(egcd(i128::MIN, 0), None);
+    assert_eq!(egcd(i128::MIN, -1), None);
+    assert_eq!(mod_inv(3_i64, 11), Some(4));
+    assert_eq!(mod_inv(-3_i64, 11), Some(7));
+    assert_eq!(mod_inv(6_u64, 9), None);
+}
+
+#[test]
+fn test_mul_pow_mod() {
+    assert_eq!(mul_mod(u64::MAX, u64::MAX, 1_000_000_007), 114944269);
+    let m = i128::MAX - 1;
+    assert_eq!(mul_mod(m - 1, m - 1, m), 1);
+    assert_eq!(pow_mod(2_u64, 10, 1000), 24);
+    assert_eq!(pow_mod(7_i64, 0, 1), 0);
+}
+
+#[test]
+fn test_crt() -> Result<()> {
+    assert_eq!(crt(&[(2_i64, 3), (3, 5), (2, 7)])?, (23, 105));
+    // Non-coprime moduli
+    assert_eq!(crt(&[(2_u64, 4), (4, 6)])?, (10, 12));
+    let err = crt(&[(1_u64, 4), (2, 6)]).unwrap_err();
+    assert_eq!(crate::error::exit_code(&err), 3);
+    let err = crt(&[(0_i64, i64::MAX), (0, i64::MAX - 1)]).unwrap_err();
+    assert_eq!(crate::error::exit_code(&err), 5);
+    Ok(())
+}
+
+#[test]
+fn test_isqrt() {
+    assert_eq!(isqrt(0_u64), 0);
+    assert_eq!(isqrt(15_i64), 3);
+    assert_eq!(isqrt(16_i128), 4);
+    assert_eq!(isqrt(u64::MAX), u32::MAX as u64);
+    assert!(is_square(1_i64 << 40));
+    assert!(!is_square(-4_i64));
+    assert_eq!((-1_i64).checked_isqrt(), None);
+}
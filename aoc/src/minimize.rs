@@ -0,0 +1,145 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Delta debugging of failing inputs
+//!
+//! [`ddmin`] is Zeller's algorithm: it finds a subset of the items that
+//! still fails and from which no single item can be removed. [`minimize`]
+//! applies it to puzzle inputs at a few granularities - blocks separated
+//! by empty lines, lines, and the columns of grids - until none of them
+//! makes progress. The predicate is expected to reject candidates that
+//! the day can't parse, which is what keeps the result a valid input.
+
+use std::collections::HashMap;
+
+/// Minimal subset of `items`, in their original order, for which
+/// `fails` is still true; `fails(items)` is assumed to be true
+pub fn ddmin<T: Clone>(items: &[T], mut fails: impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut items = items.to_vec();
+    let mut n = 2;
+    while items.len() >= 2 {
+        let chunk = items.len().div_ceil(n);
+        let chunks = (0..items.len())
+            .step_by(chunk)
+            .map(|start| start..(start + chunk).min(items.len()))
+            .collect::<Vec<_>>();
+        if let Some(range) = chunks.iter().find(|range| fails(&items[(*range).clone()])) {
+            items = items[range.clone()].to_vec();
+            n = 2;
+            continue;
+        }
+        let complement = |range: &std::ops::Range<usize>| {
+            let mut rest = items[..range.start].to_vec();
+            rest.extend_from_slice(&items[range.end..]);
+            rest
+        };
+        if let Some(rest) = chunks
+            .iter()
+            .skip_while(|_| n == 2)
+            .map(complement)
+            .find(|rest| fails(rest))
+        {
+            items = rest;
+            n = (n - 1).max(2);
+            continue;
+        }
+        if n >= items.len() {
+            break;
+        }
+        n = (2 * n).min(items.len());
+    }
+    items
+}
+
+fn join_lines(lines: &[&str]) -> String {
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/// Ranges of lines that look like a grid: at least two consecutive
+/// lines with the same length, also at least two
+fn grids(lines: &[&str]) -> Vec<std::ops::Range<usize>> {
+    let mut grids = vec![];
+    let mut start = 0;
+    for end in 1..=lines.len() {
+        if end == lines.len() || lines[end].len() != lines[start].len() {
+            if end - start >= 2 && lines[start].len() >= 2 {
+                grids.push(start..end);
+            }
+            start = end;
+        }
+    }
+    grids
+}
+
+/// Smallest input derived from `input` by removing blocks, lines and
+/// grid columns for which `fails` is still true
+pub fn minimize(input: &str, fails: impl FnMut(&str) -> bool) -> String {
+    let mut cache = HashMap::<String, bool>::new();
+    let mut fails = fails;
+    let mut fails = |candidate: String| {
+        *cache
+            .entry(candidate)
+            .or_insert_with_key(|candidate| fails(candidate))
+    };
+    let mut best = input.to_string();
+    loop {
+        let before = best.clone();
+        let blocks = best
+            .trim_end_matches('\n')
+            .split("\n\n")
+            .collect::<Vec<_>>();
+        if blocks.len() > 1 {
+            let join = |blocks: &[&str]| format!("{}\n", blocks.join("\n\n"));
+            best = join(&ddmin(&blocks, |blocks| fails(join(blocks))));
+        }
+        let lines = best.lines().collect::<Vec<_>>();
+        best = join_lines(&ddmin(&lines, |lines| fails(join_lines(lines))));
+        for grid in grids(&best.lines().collect::<Vec<_>>()) {
+            let current = best.lines().map(str::to_string).collect::<Vec<_>>();
+            let keep_columns = |columns: &[usize]| {
+                let mut candidate = current.clone();
+                for line in &mut candidate[grid.clone()] {
+                    *line = columns
+                        .iter()
+                        .filter_map(|&c| line.chars().nth(c))
+                        .collect();
+                }
+                candidate.iter().map(|line| format!("{}\n", line)).collect()
+            };
+            let columns = (0..current[grid.start].chars().count()).collect::<Vec<_>>();
+            best = keep_columns(&ddmin(&columns, |columns| fails(keep_columns(columns))));
+        }
+        if best == before {
+            return best;
+        }
+    }
+}
+
+#[test]
+fn test_ddmin() {
+    let items = (0..100).collect::<Vec<_>>();
+    let mut tests = 0;
+    let found = ddmin(&items, |items| {
+        tests += 1;
+        items.contains(&17) && items.contains(&64)
+    });
+    assert_eq!(found, vec![17, 64]);
+    assert!(tests < 100);
+    assert_eq!(ddmin(&items, |items| !items.is_empty()), vec![0]);
+}
+
+#[test]
+fn test_minimize() {
+    let input = "ab\ncd\n\n#..#\n.x..\n#..#\n\nzz\n";
+    // Needs the x, and some line after it that starts with #
+    let fails = |input: &str| {
+        let lines = input.lines().collect::<Vec<_>>();
+        lines
+            .iter()
+            .position(|l| l.contains('x'))
+            .is_some_and(|i| lines[i + 1..].iter().any(|l| l.starts_with('#')))
+    };
+    assert_eq!(minimize(input, fails), ".x\n#.\n");
+    assert_eq!(minimize("....\n.x..\n#...\n", fails), ".x\n#.\n");
+}
@@ -2,6 +2,7 @@
 // This file is subject to the terms and conditions defined in
 // file 'LICENSE', which is part of this source code package.
 
+pub use crate::error::{Location, PuzzleError};
 pub use color_eyre::eyre::eyre;
 pub use color_eyre::Report;
 pub use color_eyre::Result;
@@ -18,16 +19,26 @@ pub use nom::Finish;
 pub use nom::IResult;
 pub use std::io::BufRead;
 
+/// Parse all of `$buf` with `$parser`
 #[macro_export]
 macro_rules! parse_with {
     ($parser:expr, $buf:ident) => {{
         let mut input = String::default();
         $buf.read_to_string(&mut input)?;
         let result = all_consuming($parser)(&input).finish();
-        Ok(result.map_err(|e| eyre!("error reading input: {:?}", e))?.1)
+        Ok(result
+            .map_err(|e| $crate::parser::invalid_input(&input, e))?
+            .1)
     }};
 }
 
+/// Convert a nom error into a PuzzleError pointing at where parsing stopped
+pub fn invalid_input(input: &str, e: nom::error::Error<&str>) -> PuzzleError {
+    let location = Location::of_suffix(input, e.input);
+    let found = e.input.lines().next().unwrap_or_default();
+    PuzzleError::invalid_input_at(location, format!("unexpected {:?} ({:?})", found, e.code))
+}
+
 pub fn space(input: &str) -> IResult<&str, &str> {
     tag(" ")(input)
 }
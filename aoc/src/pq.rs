@@ -0,0 +1,253 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Min-priority queues for graph searches
+//!
+//! - [`BucketQueue`] is Dial's algorithm queue: a ring of buckets, one
+//!   per priority. It's the fastest option when edge weights are small
+//!   integers, but it's monotone: nothing can be pushed with a priority
+//!   lower than the last one popped.
+//! - [`IndexedHeap`] is a binary heap that knows where each item is, so
+//!   that pushing an item that is already queued decreases its priority
+//!   instead of adding a duplicate.
+//!
+//! Both implement [`PriorityQueue`], so a search can be written once and
+//! run with either.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Queue that pops the item with the lowest priority first
+pub trait PriorityQueue<T> {
+    fn push(&mut self, priority: usize, item: T);
+    fn pop(&mut self) -> Option<(usize, T)>;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Monotone bucket queue for priorities that grow by at most `max_step`
+/// at a time
+///
+/// Items with the same priority come out in LIFO order. Duplicates are
+/// kept, so searches should skip items that were already settled.
+#[derive(Debug, Clone)]
+pub struct BucketQueue<T> {
+    buckets: Vec<Vec<T>>,
+    current: usize,
+    len: usize,
+}
+
+impl<T> BucketQueue<T> {
+    /// Queue for pushes with priorities in `current..=current + max_step`,
+    /// `current` being the priority of the last item popped
+    pub fn new(max_step: usize) -> Self {
+        BucketQueue {
+            buckets: (0..=max_step).map(|_| vec![]).collect(),
+            current: 0,
+            len: 0,
+        }
+    }
+}
+
+impl<T> PriorityQueue<T> for BucketQueue<T> {
+    /// Panics if `priority` is out of the range the queue covers
+    fn push(&mut self, priority: usize, item: T) {
+        let nbuckets = self.buckets.len();
+        assert!(
+            (self.current..self.current + nbuckets).contains(&priority),
+            "priority {} out of {}..{}",
+            priority,
+            self.current,
+            self.current + nbuckets
+        );
+        self.buckets[priority % nbuckets].push(item);
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<(usize, T)> {
+        if self.len == 0 {
+            return None;
+        }
+        let nbuckets = self.buckets.len();
+        loop {
+            if let Some(item) = self.buckets[self.current % nbuckets].pop() {
+                self.len -= 1;
+                return Some((self.current, item));
+            }
+            self.current += 1;
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Binary heap with decrease-key
+///
+/// Each item is in the heap at most once: pushing an item that is already
+/// there keeps the lowest of the two priorities.
+#[derive(Debug, Clone)]
+pub struct IndexedHeap<T> {
+    heap: Vec<(usize, T)>,
+    index: HashMap<T, usize>,
+}
+
+impl<T> Default for IndexedHeap<T> {
+    fn default() -> Self {
+        IndexedHeap {
+            heap: vec![],
+            index: HashMap::default(),
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash> IndexedHeap<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Current priority of the item, if it is queued
+    pub fn priority(&self, item: &T) -> Option<usize> {
+        self.index.get(item).map(|&i| self.heap[i].0)
+    }
+
+    fn swap(&mut self, i: usize, j: usize) {
+        self.heap.swap(i, j);
+        self.index.insert(self.heap[i].1.clone(), i);
+        self.index.insert(self.heap[j].1.clone(), j);
+    }
+
+    fn sift_up(&mut self, mut i: usize) {
+        while i > 0 {
+            let parent = (i - 1) / 2;
+            if self.heap[parent].0 <= self.heap[i].0 {
+                break;
+            }
+            self.swap(i, parent);
+            i = parent;
+        }
+    }
+
+    fn sift_down(&mut self, mut i: usize) {
+        loop {
+            let smallest = [2 * i + 1, 2 * i + 2]
+                .into_iter()
+                .filter(|&c| c < self.heap.len())
+                .fold(i, |s, c| {
+                    if self.heap[c].0 < self.heap[s].0 {
+                        c
+                    } else {
+                        s
+                    }
+                });
+            if smallest == i {
+                break;
+            }
+            self.swap(i, smallest);
+            i = smallest;
+        }
+    }
+}
+
+impl<T: Clone + Eq + Hash> PriorityQueue<T> for IndexedHeap<T> {
+    fn push(&mut self, priority: usize, item: T) {
+        if let Some(&i) = self.index.get(&item) {
+            if priority < self.heap[i].0 {
+                self.heap[i].0 = priority;
+                self.sift_up(i);
+            }
+            return;
+        }
+        let i = self.heap.len();
+        self.index.insert(item.clone(), i);
+        self.heap.push((priority, item));
+        self.sift_up(i);
+    }
+
+    fn pop(&mut self) -> Option<(usize, T)> {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let last = self.heap.len() - 1;
+        self.swap(0, last);
+        let (priority, item) = self.heap.pop()?;
+        self.index.remove(&item);
+        self.sift_down(0);
+        Some((priority, item))
+    }
+
+    fn len(&self) -> usize {
+        self.heap.len()
+    }
+}
+
+#[cfg(test)]
+fn drain<Q: PriorityQueue<char>>(mut q: Q) -> Vec<(usize, char)> {
+    q.push(3, 'c');
+    q.push(1, 'a');
+    q.push(2, 'b');
+    q.push(5, 'e');
+    let mut popped = vec![];
+    while let Some((priority, item)) = q.pop() {
+        if item == 'a' {
+            q.push(priority + 3, 'd');
+        }
+        popped.push((priority, item));
+    }
+    popped
+}
+
+#[test]
+fn test_common() {
+    let expected = vec![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd'), (5, 'e')];
+    assert_eq!(drain(BucketQueue::new(5)), expected);
+    assert_eq!(drain(IndexedHeap::new()), expected);
+}
+
+#[test]
+fn test_bucket_queue() {
+    let mut q = BucketQueue::new(1001);
+    q.push(1000, 'x');
+    q.push(1, 'y');
+    assert_eq!(q.pop(), Some((1, 'y')));
+    q.push(1002, 'z');
+    q.push(1000, 'x');
+    assert_eq!(q.len(), 3);
+    assert_eq!(q.pop(), Some((1000, 'x')));
+    assert_eq!(q.pop(), Some((1000, 'x')));
+    assert_eq!(q.pop(), Some((1002, 'z')));
+    assert_eq!(q.pop(), None);
+    assert!(q.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn test_bucket_queue_monotone() {
+    let mut q = BucketQueue::new(3);
+    q.push(2, ());
+    q.pop();
+    q.push(1, ());
+}
+
+#[test]
+fn test_indexed_heap() {
+    let mut q = IndexedHeap::new();
+    for (i, c) in "heap".chars().enumerate() {
+        q.push(10 + i, c);
+    }
+    q.push(20, 'p');
+    assert_eq!(q.priority(&'p'), Some(13));
+    q.push(1, 'p');
+    assert_eq!(q.priority(&'p'), Some(1));
+    assert_eq!(q.len(), 4);
+    assert_eq!(q.pop(), Some((1, 'p')));
+    assert_eq!(q.pop(), Some((10, 'h')));
+    assert_eq!(q.pop(), Some((11, 'e')));
+    assert_eq!(q.pop(), Some((12, 'a')));
+    assert_eq!(q.pop(), None);
+    assert_eq!(q.priority(&'p'), None);
+}
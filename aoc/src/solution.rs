@@ -0,0 +1,187 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Registry of the solutions of each day
+//!
+//! Each day's library declares its parts with [`solutions!`](crate::solutions),
+//! giving the parameters that the bins use for the real input. Tools like
+//! `aoc report` then run them without going through the bins.
+//!
+//! A part can also have alternative implementations, named strategies,
+//! that `aoc report --cross-check` runs against the main one.
+//!
+//! Days where part b can reuse the work of part a also declare a
+//! [`Both`] with [`both!`](crate::both), that parses the input once and
+//! solves the two parts, timing each [`Phases`].
+
+use std::fmt;
+use std::io::BufRead;
+use std::time::{Duration, Instant};
+
+use crate::Result;
+
+/// One implementation of one part of one day
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub day: u32,
+    pub part: char,
+    /// Name of the alternative implementation, empty for the main one
+    pub strategy: &'static str,
+    pub run: fn(&mut dyn BufRead) -> Result<String>,
+}
+
+impl Solution {
+    /// Name of the bin of the solution, e.g. `day06a`; alternatives get
+    /// their strategy appended, e.g. `day13a:linalg`
+    pub fn name(&self) -> String {
+        if self.is_main() {
+            format!("day{:02}{}", self.day, self.part)
+        } else {
+            format!("day{:02}{}:{}", self.day, self.part, self.strategy)
+        }
+    }
+
+    /// Whether this is the implementation used by the bin
+    pub fn is_main(&self) -> bool {
+        self.strategy.is_empty()
+    }
+}
+
+impl fmt::Debug for Solution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Solution")
+            .field("day", &self.day)
+            .field("part", &self.part)
+            .field("strategy", &self.strategy)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Declare the `SOLUTIONS` of a day
+///
+/// Alternative implementations of a part have the name of their
+/// strategy after the part:
+///
+/// ```ignore
+/// solutions! {
+///     day: 13,
+///     'a' => |bufin| a::process(bufin),
+///     'a' "linalg" => |bufin| a::process_linalg(bufin),
+///     'b' => |bufin| b::process(10000000000000, bufin),
+/// }
+/// ```
+#[macro_export]
+macro_rules! solutions {
+    (@strategy) => { "" };
+    (@strategy $strategy:literal) => { $strategy };
+    (day: $day:literal, $($part:literal $($strategy:literal)? => |$bufin:ident| $body:expr),* $(,)?) => {
+        pub const SOLUTIONS: &[$crate::Solution] = &[$(
+            $crate::Solution {
+                day: $day,
+                part: $part,
+                strategy: $crate::solutions!(@strategy $($strategy)?),
+                run: |$bufin| Ok($body?.to_string()),
+            }
+        ),*];
+    };
+}
+
+/// Time taken by each phase of a run, e.g. `parse`, `a` and `b`
+#[derive(Debug, Clone)]
+pub struct Phases {
+    last: Instant,
+    pub laps: Vec<(&'static str, Duration)>,
+}
+
+impl Default for Phases {
+    fn default() -> Self {
+        Phases {
+            last: Instant::now(),
+            laps: vec![],
+        }
+    }
+}
+
+impl Phases {
+    /// End the phase `name`, that started at the previous lap
+    pub fn lap(&mut self, name: &'static str) {
+        let now = Instant::now();
+        self.laps.push((name, now - self.last));
+        self.last = now;
+    }
+
+    pub fn get(&self, name: &str) -> Option<Duration> {
+        self.laps.iter().find(|l| l.0 == name).map(|l| l.1)
+    }
+
+    pub fn total(&self) -> Duration {
+        self.laps.iter().map(|l| l.1).sum()
+    }
+}
+
+/// Both parts of one day, from a single parse
+#[derive(Clone, Copy)]
+pub struct Both {
+    pub day: u32,
+    pub run: fn(&mut dyn BufRead, &mut Phases) -> Result<(String, String)>,
+}
+
+impl fmt::Debug for Both {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Both")
+            .field("day", &self.day)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Declare the `BOTH` of a day
+///
+/// The body returns the answers of both parts, calling
+/// [`Phases::lap`] after parsing and after each part:
+///
+/// ```ignore
+/// both! {
+///     day: 16,
+///     |bufin, phases| both(bufin, phases),
+/// }
+/// ```
+#[macro_export]
+macro_rules! both {
+    (day: $day:literal, |$bufin:ident, $phases:ident| $body:expr $(,)?) => {
+        pub const BOTH: $crate::Both = $crate::Both {
+            day: $day,
+            run: |$bufin, $phases| {
+                let (a, b) = $body?;
+                Ok((a.to_string(), b.to_string()))
+            },
+        };
+    };
+}
+
+#[test]
+fn test_name() {
+    let mut solution = Solution {
+        day: 13,
+        part: 'a',
+        strategy: "",
+        run: |_| Ok(String::new()),
+    };
+    assert!(solution.is_main());
+    assert_eq!(solution.name(), "day13a");
+    solution.strategy = "linalg";
+    assert!(!solution.is_main());
+    assert_eq!(solution.name(), "day13a:linalg");
+}
+
+#[test]
+fn test_phases() {
+    let mut phases = Phases::default();
+    phases.lap("parse");
+    std::thread::sleep(Duration::from_millis(2));
+    phases.lap("a");
+    assert_eq!(phases.laps.len(), 2);
+    assert!(phases.get("a").unwrap() >= Duration::from_millis(2));
+    assert_eq!(phases.get("b"), None);
+    assert_eq!(phases.total(), phases.laps[0].1 + phases.laps[1].1);
+}
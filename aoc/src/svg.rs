@@ -0,0 +1,161 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Inline SVG pictures of grid puzzles
+//!
+//! A day with a [`Visual`], declared with [`visual!`](crate::visual),
+//! draws its grid and solution on a [`Canvas`] of colored cells, which
+//! `aoc report --html` embeds in the page. Horizontal runs of the same
+//! color become a single rectangle, which keeps the SVG of a 141x141
+//! maze small.
+
+use std::fmt::{self, Write};
+use std::io::BufRead;
+
+use crate::Result;
+
+/// Grid of colored cells, `background` where not painted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    pub width: usize,
+    pub height: usize,
+    pub background: String,
+    cells: Vec<Option<String>>,
+}
+
+impl Canvas {
+    pub fn new(width: usize, height: usize, background: &str) -> Canvas {
+        Canvas {
+            width,
+            height,
+            background: background.to_string(),
+            cells: vec![None; width * height],
+        }
+    }
+
+    /// Paint a cell with a CSS color; out-of-bounds cells are ignored
+    pub fn paint(&mut self, x: usize, y: usize, color: &str) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x] = Some(color.to_string());
+        }
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&str> {
+        self.cells.get(y * self.width + x)?.as_deref()
+    }
+
+    /// SVG element with `scale` pixels per cell
+    pub fn to_svg(&self, scale: usize) -> String {
+        let mut svg = String::new();
+        write!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+             viewBox=\"0 0 {} {}\" shape-rendering=\"crispEdges\">\
+             <rect width=\"{}\" height=\"{}\" fill=\"{}\"/>",
+            self.width * scale,
+            self.height * scale,
+            self.width,
+            self.height,
+            self.width,
+            self.height,
+            self.background
+        )
+        .unwrap();
+        for y in 0..self.height {
+            let mut x = 0;
+            while x < self.width {
+                let color = self.get(x, y);
+                let run = (x..self.width)
+                    .take_while(|&x1| self.get(x1, y) == color)
+                    .count();
+                if let Some(color) = color {
+                    write!(
+                        svg,
+                        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\" fill=\"{}\"/>",
+                        x, y, run, color
+                    )
+                    .unwrap();
+                }
+                x += run;
+            }
+        }
+        svg.push_str("</svg>");
+        svg
+    }
+}
+
+/// Color of `value` in a heatmap that goes from yellow at 1 to red at
+/// `max`
+pub fn heat(value: usize, max: usize) -> String {
+    let ratio = if max <= 1 {
+        1.0
+    } else {
+        (value.saturating_sub(1) as f64 / (max - 1) as f64).min(1.0)
+    };
+    format!("hsl({:.0}, 100%, 50%)", 60.0 * (1.0 - ratio))
+}
+
+/// Picture of the input and solution of one day
+#[derive(Clone, Copy)]
+pub struct Visual {
+    pub day: u32,
+    pub title: &'static str,
+    pub render: fn(&mut dyn BufRead) -> Result<Canvas>,
+}
+
+impl fmt::Debug for Visual {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Visual")
+            .field("day", &self.day)
+            .field("title", &self.title)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Declare the `VISUAL` of a day
+///
+/// ```ignore
+/// visual! {
+///     day: 6,
+///     title: "Route of the guard",
+///     |bufin| render(bufin),
+/// }
+/// ```
+#[macro_export]
+macro_rules! visual {
+    (day: $day:literal, title: $title:literal, |$bufin:ident| $body:expr $(,)?) => {
+        pub const VISUAL: $crate::svg::Visual = $crate::svg::Visual {
+            day: $day,
+            title: $title,
+            render: |$bufin| $body,
+        };
+    };
+}
+
+#[test]
+fn test_svg() {
+    let mut canvas = Canvas::new(3, 2, "white");
+    canvas.paint(0, 0, "red");
+    canvas.paint(1, 0, "red");
+    canvas.paint(2, 1, "blue");
+    canvas.paint(3, 1, "blue");
+    assert_eq!(canvas.get(1, 0), Some("red"));
+    assert_eq!(canvas.get(1, 1), None);
+    assert_eq!(
+        canvas.to_svg(4),
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"12\" height=\"8\" \
+         viewBox=\"0 0 3 2\" shape-rendering=\"crispEdges\">\
+         <rect width=\"3\" height=\"2\" fill=\"white\"/>\
+         <rect x=\"0\" y=\"0\" width=\"2\" height=\"1\" fill=\"red\"/>\
+         <rect x=\"2\" y=\"1\" width=\"1\" height=\"1\" fill=\"blue\"/>\
+         </svg>"
+    );
+}
+
+#[test]
+fn test_heat() {
+    assert_eq!(heat(1, 10), "hsl(60, 100%, 50%)");
+    assert_eq!(heat(10, 10), "hsl(0, 100%, 50%)");
+    assert_eq!(heat(1, 1), "hsl(0, 100%, 50%)");
+}
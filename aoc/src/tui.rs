@@ -0,0 +1,343 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Full-screen terminal stepper for simulations and searches
+//!
+//! A [`Stepper`] walks the sequence `x0, step(x0), step(step(x0))...`
+//! keeping every state, so that it can go backwards as well. [`run`]
+//! shows it in the terminal, rendering the current state with the given
+//! function:
+//!
+//! - `→`, `l`, `space`: next step; `←`, `h`: previous step
+//! - `c` / `C`: continue forward / backward to the next breakpoint
+//! - `g`: go to the step typed next, confirmed with enter
+//! - `1`..`9`: enable or disable the corresponding breakpoint
+//! - `q`, `esc`: quit
+//!
+//! Only available with the `tui` feature.
+
+use std::fmt::Write as _;
+use std::io::{self, stdout, Write};
+
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+
+use crate::Result;
+
+/// Maximum number of steps taken by a single continue
+pub const CONTINUE_LIMIT: usize = 1_000_000;
+
+type StepFn<'a, S> = Box<dyn FnMut(&S) -> Option<S> + 'a>;
+
+pub struct Breakpoint<'a, S> {
+    pub name: String,
+    pub enabled: bool,
+    predicate: Box<dyn Fn(&S) -> bool + 'a>,
+}
+
+pub struct Stepper<'a, S> {
+    history: Vec<S>,
+    index: usize,
+    ended: bool,
+    step: StepFn<'a, S>,
+    breakpoints: Vec<Breakpoint<'a, S>>,
+}
+
+impl<'a, S> Stepper<'a, S> {
+    /// Stepper that starts at `x0`; `step` returns None when the
+    /// simulation ends
+    pub fn new<F>(x0: S, step: F) -> Self
+    where
+        F: FnMut(&S) -> Option<S> + 'a,
+    {
+        Stepper {
+            history: vec![x0],
+            index: 0,
+            ended: false,
+            step: Box::new(step),
+            breakpoints: vec![],
+        }
+    }
+
+    /// Add a breakpoint that stops continues at the states for which
+    /// `predicate` is true
+    pub fn breakpoint<P>(mut self, name: &str, predicate: P) -> Self
+    where
+        P: Fn(&S) -> bool + 'a,
+    {
+        self.breakpoints.push(Breakpoint {
+            name: name.to_string(),
+            enabled: true,
+            predicate: Box::new(predicate),
+        });
+        self
+    }
+
+    pub fn breakpoints(&self) -> &[Breakpoint<'a, S>] {
+        &self.breakpoints
+    }
+
+    /// Enable or disable the breakpoint `i`, if it exists
+    pub fn toggle(&mut self, i: usize) {
+        if let Some(bp) = self.breakpoints.get_mut(i) {
+            bp.enabled = !bp.enabled;
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn state(&self) -> &S {
+        &self.history[self.index]
+    }
+
+    /// Index of the last state, once the simulation got there
+    pub fn last(&self) -> Option<usize> {
+        self.ended.then_some(self.history.len() - 1)
+    }
+
+    /// Move one step forward; false at the end of the simulation
+    pub fn forward(&mut self) -> bool {
+        if self.index + 1 < self.history.len() {
+            self.index += 1;
+            return true;
+        }
+        if self.ended {
+            return false;
+        }
+        match (self.step)(&self.history[self.index]) {
+            Some(next) => {
+                self.history.push(next);
+                self.index += 1;
+                true
+            }
+            None => {
+                self.ended = true;
+                false
+            }
+        }
+    }
+
+    /// Move one step backward; false at the start
+    pub fn backward(&mut self) -> bool {
+        if self.index == 0 {
+            return false;
+        }
+        self.index -= 1;
+        true
+    }
+
+    /// Go to step `n`, or as close to it as the simulation gets
+    pub fn goto(&mut self, n: usize) -> usize {
+        while self.index < n && self.forward() {}
+        self.index = self.index.min(n);
+        self.index
+    }
+
+    /// Name of the first enabled breakpoint that matches the current state
+    pub fn hit(&self) -> Option<&str> {
+        let state = self.state();
+        self.breakpoints
+            .iter()
+            .find(|bp| bp.enabled && (bp.predicate)(state))
+            .map(|bp| bp.name.as_str())
+    }
+
+    /// Step forward until a breakpoint matches, at most `limit` steps;
+    /// returns the name of the breakpoint
+    pub fn continue_forward(&mut self, limit: usize) -> Option<&str> {
+        for _ in 0..limit {
+            if !self.forward() {
+                return None;
+            }
+            if self.hit().is_some() {
+                return self.hit();
+            }
+        }
+        None
+    }
+
+    /// Step backward until a breakpoint matches; returns its name
+    pub fn continue_backward(&mut self) -> Option<&str> {
+        while self.backward() {
+            if self.hit().is_some() {
+                return self.hit();
+            }
+        }
+        None
+    }
+
+    /// The status bar: step number and breakpoints
+    pub fn status(&self) -> String {
+        let mut status = format!("step {}", self.index);
+        if let Some(last) = self.last() {
+            write!(status, "/{}", last).unwrap();
+        }
+        for (i, bp) in self.breakpoints.iter().enumerate() {
+            let mark = if bp.enabled { '*' } else { ' ' };
+            write!(status, " | [{}]{}{}", i + 1, mark, bp.name).unwrap();
+        }
+        status
+    }
+}
+
+/// Leaves the terminal the way it was found when dropped
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Screen> {
+        terminal::enable_raw_mode()?;
+        execute!(stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn draw<S>(
+    out: &mut impl Write,
+    stepper: &Stepper<S>,
+    body: &str,
+    message: &str,
+) -> io::Result<()> {
+    let (cols, rows) = terminal::size()?;
+    let cols = cols as usize;
+    let rows = rows.max(3);
+    queue!(out, Clear(ClearType::All))?;
+    for (y, line) in body.lines().take(rows as usize - 2).enumerate() {
+        let line = line.chars().take(cols).collect::<String>();
+        queue!(out, MoveTo(0, y as u16), Print(line))?;
+    }
+    let status = stepper.status().chars().take(cols).collect::<String>();
+    let message = message.chars().take(cols).collect::<String>();
+    queue!(out, MoveTo(0, rows - 2), Print(status))?;
+    queue!(out, MoveTo(0, rows - 1), Print(message))?;
+    out.flush()
+}
+
+const HELP: &str = "→/← step, c/C continue, g goto, 1-9 toggle breakpoint, q quit";
+
+/// Show the stepper in the terminal until the user quits
+///
+/// The keyboard is read from the terminal even when stdin is redirected,
+/// so the input can be piped in as usual.
+pub fn run<S, R>(mut stepper: Stepper<S>, render: R) -> Result<()>
+where
+    R: Fn(&S) -> String,
+{
+    let _screen = Screen::enter()?;
+    let mut out = stdout();
+    let mut goto: Option<String> = None;
+    let mut message = HELP.to_string();
+    loop {
+        let prompt = goto.as_ref().map(|n| format!("go to step: {}", n));
+        draw(
+            &mut out,
+            &stepper,
+            &render(stepper.state()),
+            prompt.as_ref().unwrap_or(&message),
+        )?;
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        if let Some(n) = &mut goto {
+            match key.code {
+                KeyCode::Char(c) if c.is_ascii_digit() => n.push(c),
+                KeyCode::Backspace => {
+                    n.pop();
+                }
+                KeyCode::Enter => {
+                    let target = n.parse().unwrap_or(0);
+                    let reached = stepper.goto(target);
+                    message = if reached < target {
+                        format!("simulation ended at step {}", reached)
+                    } else {
+                        HELP.to_string()
+                    };
+                    goto = None;
+                }
+                _ => goto = None,
+            }
+            continue;
+        }
+        message = HELP.to_string();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => {
+                if stepper.forward() {
+                    continue;
+                }
+                message = "end of the simulation".to_string();
+            }
+            KeyCode::Left | KeyCode::Char('h') => {
+                if stepper.backward() {
+                    continue;
+                }
+                message = "start of the simulation".to_string();
+            }
+            KeyCode::Char('c') => {
+                message = match stepper.continue_forward(CONTINUE_LIMIT) {
+                    Some(name) => format!("breakpoint: {}", name),
+                    None => "no breakpoint ahead".to_string(),
+                };
+            }
+            KeyCode::Char('C') => {
+                message = match stepper.continue_backward() {
+                    Some(name) => format!("breakpoint: {}", name),
+                    None => "no breakpoint behind".to_string(),
+                };
+            }
+            KeyCode::Char('g') => goto = Some(String::new()),
+            KeyCode::Char(c @ '1'..='9') => stepper.toggle(c as usize - '1' as usize),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test_stepper() {
+    // 0, 1, 2, ..., 10
+    let mut stepper = Stepper::new(0, |x| (*x < 10).then_some(x + 1))
+        .breakpoint("odd", |x| x % 2 == 1)
+        .breakpoint("seven", |x| *x == 7);
+    assert!(!stepper.backward());
+    assert!(stepper.forward());
+    assert_eq!(*stepper.state(), 1);
+    assert_eq!(stepper.hit(), Some("odd"));
+    stepper.toggle(0);
+    assert_eq!(stepper.hit(), None);
+    assert_eq!(stepper.continue_forward(CONTINUE_LIMIT), Some("seven"));
+    assert_eq!(stepper.index(), 7);
+    assert_eq!(stepper.status(), "step 7 | [1] odd | [2]*seven");
+    assert_eq!(stepper.continue_forward(CONTINUE_LIMIT), None);
+    assert_eq!(stepper.index(), 10);
+    assert_eq!(stepper.last(), Some(10));
+    assert!(!stepper.forward());
+    stepper.toggle(0);
+    assert_eq!(stepper.continue_backward(), Some("odd"));
+    assert_eq!(stepper.index(), 9);
+    assert_eq!(stepper.goto(3), 3);
+    assert_eq!(stepper.goto(50), 10);
+    assert_eq!(stepper.status(), "step 10/10 | [1]*odd | [2]*seven");
+}
+
+#[test]
+fn test_stepper_limit() {
+    let mut stepper = Stepper::new(0_u64, |x| Some(x + 1)).breakpoint("never", |_| false);
+    assert_eq!(stepper.continue_forward(100), None);
+    assert_eq!(stepper.index(), 100);
+    assert_eq!(stepper.last(), None);
+}
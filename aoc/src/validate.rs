@@ -0,0 +1,214 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Semantic checks of parsed inputs
+//!
+//! The nom parsers only check the syntax; the invariants the solvers
+//! rely on, like a grid having exactly one start, are checked by the
+//! day's `validate`, which collects every [`Violation`] instead of
+//! stopping at the first. The day's parser then fails with
+//! [`Violations::into_result`], and `aoc check` lists them all through
+//! the [`Validator`] declared with [`validator!`](crate::validator).
+
+use std::fmt::{self, Display};
+use std::io::BufRead;
+
+use crate::error::{Location, PuzzleError};
+use crate::Result;
+
+/// Invariant broken by the input, and where
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Violation {
+    pub location: Option<Location>,
+    pub message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location {
+            Some(location) => write!(f, "{}: {}", location, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Violations(pub Vec<Violation>);
+
+impl Violations {
+    pub fn add<M: Display>(&mut self, message: M) {
+        self.0.push(Violation {
+            location: None,
+            message: message.to_string(),
+        });
+    }
+
+    pub fn at<M: Display>(&mut self, location: Location, message: M) {
+        self.0.push(Violation {
+            location: Some(location),
+            message: message.to_string(),
+        });
+    }
+
+    /// Check that `what` was found exactly once, given where it was found
+    pub fn expect_one(&mut self, what: &str, found: &[Location]) {
+        match found {
+            [] => self.add(format!("no {} found", what)),
+            [_] => {}
+            [first, rest @ ..] => {
+                for location in rest {
+                    self.at(*location, format!("extra {}, first one at {}", what, first));
+                }
+            }
+        }
+    }
+
+    /// Check that exactly one cell of the grid is `what`
+    pub fn expect_one_cell<T>(&mut self, what: &str, grid: &[Vec<T>], pred: impl Fn(&T) -> bool) {
+        self.expect_one(what, &cells_where(grid, pred));
+    }
+
+    /// Check that all the lines of a grid have the width of the first
+    pub fn expect_rectangular<T>(&mut self, grid: &[Vec<T>]) {
+        let Some(width) = grid.first().map(Vec::len) else {
+            return;
+        };
+        for (y, line) in grid.iter().enumerate() {
+            if line.len() != width {
+                self.at(
+                    Location::from_xy(0, y),
+                    format!("line has {} cells, expected {}", line.len(), width),
+                );
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Violation> {
+        self.0.iter()
+    }
+
+    /// Ok if there are no violations, otherwise an
+    /// [`InvalidInput`](PuzzleError::InvalidInput) with the first one
+    pub fn into_result(self) -> Result<()> {
+        let Some(first) = self.0.first() else {
+            return Ok(());
+        };
+        let mut message = first.message.clone();
+        if self.0.len() > 1 {
+            message = format!("{} (and {} more)", message, self.0.len() - 1);
+        }
+        Err(PuzzleError::InvalidInput {
+            location: first.location,
+            message,
+        }
+        .into())
+    }
+}
+
+/// Locations of the cells of the grid for which `pred` is true
+pub fn cells_where<T>(grid: &[Vec<T>], pred: impl Fn(&T) -> bool) -> Vec<Location> {
+    grid.iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.iter()
+                .enumerate()
+                .filter(|(_, cell)| pred(cell))
+                .map(move |(x, _)| Location::from_xy(x, y))
+        })
+        .collect()
+}
+
+/// Semantic checks of the input of one day
+#[derive(Clone, Copy)]
+pub struct Validator {
+    pub day: u32,
+    /// Parses the input, failing only on syntax errors, and checks it
+    pub run: fn(&mut dyn BufRead) -> Result<Violations>,
+}
+
+impl fmt::Debug for Validator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Validator")
+            .field("day", &self.day)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Declare the `VALIDATOR` of a day
+///
+/// ```ignore
+/// validator! {
+///     day: 6,
+///     |bufin| Ok(validate(&parser::parse0(bufin)?)),
+/// }
+/// ```
+#[macro_export]
+macro_rules! validator {
+    (day: $day:literal, |$bufin:ident| $body:expr $(,)?) => {
+        pub const VALIDATOR: $crate::validate::Validator = $crate::validate::Validator {
+            day: $day,
+            run: |$bufin| $body,
+        };
+    };
+}
+
+#[test]
+fn test_expect_one() {
+    let mut v = Violations::default();
+    v.expect_one("guard", &[Location::from_xy(1, 2)]);
+    assert!(v.is_empty());
+    assert!(v.clone().into_result().is_ok());
+    v.expect_one("start", &[]);
+    v.expect_one("end", &[Location::from_xy(0, 0), Location::from_xy(3, 4)]);
+    assert_eq!(v.len(), 2);
+    assert_eq!(v.0[0].to_string(), "no start found");
+    assert_eq!(
+        v.0[1].to_string(),
+        "line 5, column 4: extra end, first one at line 1, column 1"
+    );
+    let report = v.into_result().unwrap_err();
+    assert_eq!(
+        report.downcast_ref::<PuzzleError>(),
+        Some(&PuzzleError::invalid_input("no start found (and 1 more)"))
+    );
+}
+
+#[test]
+fn test_expect_rectangular() {
+    let mut v = Violations::default();
+    v.expect_rectangular(&[vec![1, 2], vec![3, 4]]);
+    assert!(v.is_empty());
+    v.expect_rectangular(&[vec![1, 2], vec![3], vec![4, 5]]);
+    assert_eq!(
+        v.0,
+        vec![Violation {
+            location: Some(Location { line: 2, column: 1 }),
+            message: "line has 1 cells, expected 2".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_expect_one_cell() {
+    let grid = ["#####", "#S.S#", "#..#", "#####"].map(|line| line.chars().collect::<Vec<_>>());
+    assert_eq!(
+        cells_where(&grid, |c| *c == 'S'),
+        vec![Location::from_xy(1, 1), Location::from_xy(3, 1)]
+    );
+    let mut v = Violations::default();
+    v.expect_rectangular(&grid);
+    v.expect_one_cell("start", &grid, |c| *c == 'S');
+    v.expect_one_cell("end", &grid, |c| *c == 'E');
+    assert_eq!(v.len(), 3);
+    assert_eq!(v.0[1].location, Some(Location { line: 2, column: 4 }));
+    assert_eq!(v.0[2].to_string(), "no end found");
+}
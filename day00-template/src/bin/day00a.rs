@@ -4,17 +4,6 @@
 
 use day00::*;
 
-fn process(bufin: impl BufRead) -> Result<usize> {
-    let input = parser::parse(bufin)?;
-    Ok(input.len())
-}
-
-#[test]
-fn test() -> Result<()> {
-    assert_eq!(process(EXAMPLE.as_bytes())?, 1);
-    Ok(())
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(stdin().lock()))
+    do_main(|| a::process(stdin().lock()))
 }
@@ -4,7 +4,20 @@
 
 pub use aoc::*;
 
-pub const EXAMPLE: &str = "0\n";
+pub mod a;
+
+solutions! {
+    day: 0,
+    'a' => |bufin| a::process(bufin),
+}
+
+dumper! {
+    day: 0,
+    parser::parse,
+    parser::unparse,
+}
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub mod parser {
     use aoc::parser::*;
@@ -24,6 +37,10 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<u32>> {
         aoc::parse_with!(multi::many1(line), bufin)
     }
+
+    pub fn unparse(nums: &[u32]) -> String {
+        nums.iter().map(|num| format!("{}\n", num)).collect()
+    }
 }
 
 #[test]
@@ -0,0 +1,25 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::collections::HashMap;
+
+pub fn process(bufin: impl BufRead) -> Result<i32> {
+    let input = parser::parse(bufin)?;
+    let (left, right): (Vec<_>, Vec<_>) = input.into_iter().unzip();
+    let counts = right
+        .into_iter()
+        .fold(HashMap::<i32, i32>::default(), |mut counts, i| {
+            *counts.entry(i).or_default() += 1;
+            counts
+        });
+    let score = left
+        .into_iter()
+        .map(|i| i * counts.get(&i).unwrap_or(&0))
+        .sum();
+    Ok(score)
+}
+
+example_tests!("b", process);
@@ -4,23 +4,6 @@
 
 use day01::*;
 
-fn process(bufin: impl BufRead) -> Result<i32> {
-    let input = parser::parse(bufin)?;
-    let (mut left, mut right): (Vec<_>, Vec<_>) = input.into_iter().unzip();
-    left.sort();
-    right.sort();
-    let result = std::iter::zip(left, right)
-        .map(|(l, r)| (l - r).abs())
-        .sum();
-    Ok(result)
-}
-
-#[test]
-fn test() -> Result<()> {
-    assert_eq!(process(EXAMPLE.as_bytes())?, 11);
-    Ok(())
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(stdin().lock()))
+    do_main(|| a::process(stdin().lock()))
 }
@@ -0,0 +1,46 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use aoc::generator::{self, Rng};
+
+/// `size` lines with two location IDs, drawn from a pool small enough
+/// to have repetitions
+pub struct Gen;
+
+impl generator::Generator for Gen {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let pool = (0..size.div_ceil(2).max(1))
+            .map(|_| rng.between(10000, 99999))
+            .collect::<Vec<_>>();
+        (0..size)
+            .map(|_| format!("{}   {}\n", rng.pick(&pool), rng.pick(&pool)))
+            .collect()
+    }
+}
+
+/// Pair the smallest remaining of each list, one at a time
+pub fn reference_a(input: &str) -> Result<i32> {
+    let (mut left, mut right): (Vec<_>, Vec<_>) =
+        parser::parse(input.as_bytes())?.into_iter().unzip();
+    let mut total = 0;
+    while !left.is_empty() {
+        let l = left.remove((0..left.len()).min_by_key(|&i| left[i]).unwrap());
+        let r = right.remove((0..right.len()).min_by_key(|&i| right[i]).unwrap());
+        total += (l - r).abs();
+    }
+    Ok(total)
+}
+
+/// Count the occurrences in the right list of each left number
+pub fn reference_b(input: &str) -> Result<i32> {
+    let (left, right): (Vec<_>, Vec<_>) = parser::parse(input.as_bytes())?.into_iter().unzip();
+    Ok(left
+        .iter()
+        .map(|l| l * right.iter().filter(|r| *r == l).count() as i32)
+        .sum())
+}
+
+generator_tests!(Gen, 20, 100, a => reference_a, b => reference_b);
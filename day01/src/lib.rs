@@ -4,13 +4,25 @@
 
 pub use aoc::*;
 
-pub const EXAMPLE: &str = "3   4
-4   3
-2   5
-1   3
-3   9
-3   3
-";
+pub mod a;
+pub mod b;
+pub mod generator;
+
+solutions! {
+    day: 1,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 1,
+    parser::parse,
+    parser::unparse,
+}
+
+pub const GENERATOR: &dyn aoc::generator::Generator = &generator::Gen;
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub mod parser {
     use aoc::parser::*;
@@ -32,6 +44,13 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<(i32, i32)>> {
         aoc::parse_with!(multi::many1(line), bufin)
     }
+
+    pub fn unparse(pairs: &[(i32, i32)]) -> String {
+        pairs
+            .iter()
+            .map(|(n1, n2)| format!("{}   {}\n", n1, n2))
+            .collect()
+    }
 }
 
 #[test]
@@ -0,0 +1,21 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(bufin: impl BufRead) -> Result<usize> {
+    let input = parser::parse(bufin)?;
+    let safe = input
+        .iter()
+        .filter(|report| {
+            let increasing = report[1] - report[0] > 0;
+            std::iter::zip(report.iter(), report.iter().skip(1)).all(|(i, j)| {
+                increasing && i < j && j - i <= 3 || !increasing && i > j && i - j <= 3
+            })
+        })
+        .count();
+    Ok(safe)
+}
+
+example_tests!("a", process);
@@ -4,13 +4,22 @@
 
 pub use aoc::*;
 
-pub const EXAMPLE: &str = "7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9
-";
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 2,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 2,
+    parser::parse,
+    parser::unparse,
+}
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub mod parser {
     use aoc::parser::*;
@@ -30,6 +39,16 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<i32>>> {
         aoc::parse_with!(multi::many1(line), bufin)
     }
+
+    pub fn unparse(reports: &[Vec<i32>]) -> String {
+        reports
+            .iter()
+            .map(|nums| {
+                let nums = nums.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+                format!("{}\n", nums.join(" "))
+            })
+            .collect()
+    }
 }
 
 #[test]
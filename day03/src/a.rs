@@ -0,0 +1,40 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use regex::Regex;
+
+pub fn process(bufin: impl BufRead) -> Result<u64> {
+    let input = std::io::read_to_string(bufin)?;
+    let re = Regex::new(r"mul\(([0-9]+),([0-9]+)\)").unwrap();
+    Ok(re
+        .captures_iter(&input)
+        .map(|m| {
+            let (_, [n1, n2]) = m.extract();
+            n1.parse::<u64>().unwrap() * n2.parse::<u64>().unwrap()
+        })
+        .sum())
+}
+
+/// Same as [`process`], but with the nom parser of part b
+pub fn process_nom(bufin: impl BufRead) -> Result<u64> {
+    let input = parser::parse(bufin)?;
+    Ok(input
+        .into_iter()
+        .map(|instr| match instr {
+            Instr::Mul(n1, n2) => n1 * n2,
+            _ => 0,
+        })
+        .sum())
+}
+
+#[test]
+fn test_nom() -> Result<()> {
+    assert_eq!(process_nom(EXAMPLE1.as_bytes())?, 161);
+    assert_eq!(process_nom(EXAMPLE2.as_bytes())?, 161);
+    Ok(())
+}
+
+example_tests!("a", process);
@@ -3,14 +3,29 @@
 // file 'LICENSE', which is part of this source code package.
 
 pub use aoc::*;
+use serde::{Deserialize, Serialize};
 
-pub const EXAMPLE1: &str =
-    "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))\n";
+pub mod a;
+pub mod b;
 
-pub const EXAMPLE2: &str =
-    "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n";
+solutions! {
+    day: 3,
+    'a' => |bufin| a::process(bufin),
+    'a' "nom" => |bufin| a::process_nom(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 3,
+    parser::parse,
+    parser::unparse,
+}
+
+pub const EXAMPLE1: &str = include_str!("../examples/example1.txt");
+
+pub const EXAMPLE2: &str = include_str!("../examples/example2.txt");
 
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum Instr {
     Do,
     Dont,
@@ -50,13 +65,25 @@ pub mod parser {
         branch::alt((parse_do, parse_dont, parse_mul, parse_corruption))(input)
     }
 
+    fn instrs(input: &str) -> IResult<&str, Vec<Instr>> {
+        combinator::map(multi::many1(token), |opts| {
+            opts.into_iter().flatten().collect()
+        })(input)
+    }
+
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Instr>> {
-        aoc::parse_with!(multi::many1(token), bufin).map(|opts| {
-            opts.into_iter()
-                .filter(|opt| opt.is_some())
-                .collect::<Option<Vec<Instr>>>()
-                .unwrap()
-        })
+        aoc::parse_with!(instrs, bufin)
+    }
+
+    pub fn unparse(instrs: &[Instr]) -> String {
+        instrs
+            .iter()
+            .map(|instr| match instr {
+                Instr::Do => "do()".to_string(),
+                Instr::Dont => "don't()".to_string(),
+                Instr::Mul(n1, n2) => format!("mul({},{})", n1, n2),
+            })
+            .collect()
     }
 }
 
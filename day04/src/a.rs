@@ -0,0 +1,22 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use sqrid::Dir;
+
+pub fn process(bufin: impl BufRead) -> Result<usize> {
+    let input = parser::parse(bufin)?;
+    let mut count = 0;
+    for y in 0..input.len() {
+        for x in 0..input[y].len() {
+            for dir in Dir::iter::<true>() {
+                count += check(&input, (x, y), dir, "XMAS");
+            }
+        }
+    }
+    Ok(count)
+}
+
+example_tests!("a", process);
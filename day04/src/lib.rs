@@ -4,26 +4,26 @@
 
 pub use aoc::*;
 
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 4,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 4,
+    parser::parse,
+    parser::unparse,
+}
+
 use sqrid::Dir;
 
-pub const EXAMPLE0: &str = "..X...
-.SAMX.
-.A..A.
-XMAS.S
-.X....
-";
+pub const EXAMPLE0: &str = include_str!("../examples/example0.txt");
 
-pub const EXAMPLE: &str = "MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX
-";
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub mod parser {
     use aoc::parser::*;
@@ -37,6 +37,10 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<char>>> {
         aoc::parse_with!(multi::many1(line), bufin)
     }
+
+    pub fn unparse(grid: &[Vec<char>]) -> String {
+        aoc::dump::grid_text(grid, |c| *c)
+    }
 }
 
 #[test]
@@ -0,0 +1,57 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::collections::HashSet;
+
+use aoc::graph::Graph;
+
+type Rules = HashSet<(u32, u32)>;
+
+/// Reorder the update so that it follows the rules, or None if it already
+/// does
+fn fix(rules: &Rules, update: Vec<u32>) -> Result<Option<Vec<u32>>> {
+    let mut graph = Graph::new();
+    for &page in &update {
+        graph.add_node(page);
+    }
+    for &(before, after) in rules {
+        if graph.contains(&before) && graph.contains(&after) {
+            graph.add_edge(before, after);
+        }
+    }
+    let fixed = graph.toposort()?;
+    Ok((fixed != update).then_some(fixed))
+}
+
+/// Middle pages of the updates that had to be fixed, after fixing them
+pub fn middles(bufin: impl BufRead) -> Result<Vec<u32>> {
+    let (rules0, updates) = parser::parse(bufin)?;
+    let rules = rules0.into_iter().collect::<Rules>();
+    let mut middles = vec![];
+    for upd in updates {
+        if let Some(upd) = fix(&rules, upd)? {
+            middles.push(upd[upd.len() / 2]);
+        }
+    }
+    Ok(middles)
+}
+
+pub fn process(bufin: impl BufRead) -> Result<u32> {
+    Ok(middles(bufin)?.into_iter().sum())
+}
+
+#[test]
+fn test_fix() -> Result<()> {
+    // Pages without rules keep their relative order
+    let rules = [(5, 2), (2, 7)].into_iter().collect::<Rules>();
+    assert_eq!(fix(&rules, vec![9, 2, 1, 5])?, Some(vec![9, 1, 5, 2]));
+    assert_eq!(fix(&rules, vec![5, 2, 7])?, None);
+    let rules = [(1, 2), (2, 1)].into_iter().collect::<Rules>();
+    assert!(fix(&rules, vec![1, 2]).is_err());
+    Ok(())
+}
+
+example_tests!("b", process);
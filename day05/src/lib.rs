@@ -4,35 +4,53 @@
 
 pub use aoc::*;
 
-pub const EXAMPLE: &str = "47|53
-97|13
-97|61
-97|47
-75|29
-61|13
-75|53
-29|13
-97|29
-53|29
-61|53
-97|53
-61|29
-47|13
-75|47
-97|75
-47|61
-75|61
-47|29
-75|13
-53|13
-
-75,47,61,53,29
-97,61,53,29,13
-75,29,13
-75,97,47,61,53
-61,13,29
-97,13,75,29,47
-";
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 5,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 5,
+    parser::parse,
+    parser::unparse,
+}
+
+anonymizer! {
+    day: 5,
+    labels: |input, rng| {
+        let (rules, updates) = parser::parse(input.as_bytes())?;
+        let pages = rules
+            .into_iter()
+            .flat_map(|(p1, p2)| [p1, p2])
+            .chain(updates.into_iter().flatten())
+            .map(|p| p.to_string())
+            .collect::<Vec<_>>();
+        // Same number of digits, so that the input keeps its layout
+        anonymize::rename(
+            rng,
+            pages.iter().map(String::as_str),
+            (10..=99).map(|p: u32| p.to_string()),
+        )
+    },
+    expected: |part, input, _answer, labels| {
+        let middles = if part == 'a' {
+            a::middles(input.as_bytes())?
+        } else {
+            b::middles(input.as_bytes())?
+        };
+        let sum = middles
+            .into_iter()
+            .map(|p| anonymize::relabel(&p.to_string(), labels).parse::<u32>())
+            .sum::<Result<u32, _>>()?;
+        Ok(sum.to_string())
+    },
+}
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub type Rule = (u32, u32);
 
@@ -69,6 +87,21 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<(Vec<Rule>, Vec<Vec<u32>>)> {
         aoc::parse_with!(both, bufin)
     }
+
+    pub fn unparse((rules, updates): &(Vec<Rule>, Vec<Vec<u32>>)) -> String {
+        let rules = rules
+            .iter()
+            .map(|(n1, n2)| format!("{}|{}\n", n1, n2))
+            .collect::<String>();
+        let updates = updates
+            .iter()
+            .map(|update| {
+                let update = update.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+                format!("{}\n", update.join(","))
+            })
+            .collect::<String>();
+        format!("{}\n{}", rules, updates)
+    }
 }
 
 #[test]
@@ -78,3 +111,9 @@ fn test() -> Result<()> {
     assert_eq!(input.1.len(), 6);
     Ok(())
 }
+
+#[test]
+fn test_anonymize() -> Result<()> {
+    ANONYMIZER.check_example(SOLUTIONS, EXAMPLE)?;
+    Ok(())
+}
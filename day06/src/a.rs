@@ -0,0 +1,19 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(bufin: impl BufRead, size: u16) -> Result<usize> {
+    let (walls, guard) = parser::parse(bufin)?;
+    let mut visited = PosSet::<Pos>::new();
+    visited.insert(guard);
+    let mut state = (guard, Dir::N);
+    while let Some(next) = guard_step((size, size), |p| walls.contains(&p), state) {
+        visited.insert(next.0);
+        state = next;
+    }
+    Ok(visited.len())
+}
+
+example_tests!("a", |bufin, params| process(bufin, params.get("size")?));
@@ -0,0 +1,76 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+fn get_next(size: u16, guard: Pos, d: Dir) -> Option<Pos> {
+    (guard + d).ok().filter(|n| n.x() < size && n.y() < size)
+}
+
+/// Whether the guard walks in a cycle with `new_wall`
+///
+/// Only turns can repeat, as a cycle must have at least one, so the
+/// states that [`cycle::brent`] compares are the ones right after each
+/// turn.
+fn check_cycle(guard: Pos, walls: &PosSet<Pos>, size: u16, new_wall: Pos) -> bool {
+    let is_wall = |p| walls.contains(&p) || p == new_wall;
+    let next_turn = |&state: &(Pos, Dir)| {
+        let mut state = state;
+        loop {
+            let next = guard_step((size, size), is_wall, state)?;
+            if next.1 != state.1 {
+                return Some(next);
+            }
+            state = next;
+        }
+    };
+    cycle::brent((guard, Dir::N), next_turn).is_some()
+}
+
+fn do_process(bufin: impl BufRead, size: u16) -> Result<PosSet<Pos>> {
+    let (walls, guard0) = parser::parse(bufin)?;
+    let mut guard = guard0;
+    // Extra obstructions that would create a cycle
+    let mut extra = PosSet::<Pos>::new();
+    let mut d = Dir::N;
+    while let Some(next) = get_next(size, guard, d) {
+        if walls.contains(&next) {
+            d += Dir::E;
+        } else {
+            if next != guard0 {
+                // Check what happens if we put a rock right at next:
+                if check_cycle(guard0, &walls, size, next) {
+                    extra.insert(next);
+                }
+            }
+            guard = next;
+        }
+    }
+    Ok(extra)
+}
+
+pub fn process(bufin: impl BufRead, size: u16) -> Result<usize> {
+    Ok(do_process(bufin, size)?.len())
+}
+
+#[test]
+fn test_found() -> Result<()> {
+    let mut ans = vec![
+        Pos::new_unwrap(3, 6),
+        Pos::new_unwrap(6, 7),
+        Pos::new_unwrap(7, 7),
+        Pos::new_unwrap(1, 8),
+        Pos::new_unwrap(3, 8),
+        Pos::new_unwrap(7, 9),
+    ];
+    ans.sort();
+    let mut calc = do_process(EXAMPLE.as_bytes(), 10)?
+        .iter()
+        .collect::<Vec<_>>();
+    calc.sort();
+    assert_eq!(calc, ans);
+    Ok(())
+}
+
+example_tests!("b", |bufin, params| process(bufin, params.get("size")?));
@@ -0,0 +1,101 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Step through the guard's route; the map size is the optional argument
+
+use day06::*;
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    step: usize,
+    guard: Pos,
+    dir: Dir,
+    /// Number of positions visited so far
+    visited: usize,
+    revisit: bool,
+}
+
+/// The whole route, until the guard leaves or walks in a cycle
+fn route(walls: &PosSet<Pos>, size: u16, guard: Pos) -> Vec<(Pos, Dir)> {
+    let mut seen = PosDirSet::<Pos>::new();
+    let mut state = (guard, Dir::N);
+    let mut route = vec![state];
+    seen.insert(guard, Dir::N);
+    while let Some(next) = guard_step((size, size), |p| walls.contains(&p), state) {
+        if !seen.insert(next.0, next.1) {
+            break;
+        }
+        route.push(next);
+        state = next;
+    }
+    route
+}
+
+/// Step in which each position is first visited, so that the states
+/// don't have to carry the visited positions
+fn first_visits(route: &[(Pos, Dir)]) -> PosMap<Pos, usize> {
+    let mut first = PosMap::new(usize::MAX);
+    for (step, (pos, _)) in route.iter().enumerate().rev() {
+        first[*pos] = step;
+    }
+    first
+}
+
+fn render(walls: &PosSet<Pos>, first: &PosMap<Pos, usize>, size: u16, state: &State) -> String {
+    let mut out = String::new();
+    for y in 0..size {
+        for x in 0..size {
+            let pos = Pos::new_unwrap(x, y);
+            out.push(if pos == state.guard {
+                match state.dir {
+                    Dir::N => '^',
+                    Dir::E => '>',
+                    Dir::S => 'v',
+                    _ => '<',
+                }
+            } else if walls.contains(&pos) {
+                '#'
+            } else if first[pos] <= state.step {
+                'X'
+            } else {
+                '.'
+            });
+        }
+        out.push('\n');
+    }
+    out.push_str(&format!("visited: {}\n", state.visited));
+    out
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    let size = std::env::args().nth(1).map_or(Ok(130), |s| s.parse())?;
+    let (walls, guard) = parser::parse(stdin().lock())?;
+    let route = route(&walls, size, guard);
+    let first = first_visits(&route);
+    let x0 = State {
+        step: 0,
+        guard,
+        dir: Dir::N,
+        visited: 1,
+        revisit: false,
+    };
+    let stepper = tui::Stepper::new(x0, |s: &State| {
+        let step = s.step + 1;
+        let &(guard, dir) = route.get(step)?;
+        let new = first[guard] == step;
+        Some(State {
+            step,
+            guard,
+            dir,
+            visited: s.visited + usize::from(new),
+            revisit: guard != s.guard && !new,
+        })
+    })
+    .breakpoint("wall ahead", |s| {
+        (s.guard + s.dir).is_ok_and(|p| walls.contains(&p))
+    })
+    .breakpoint("revisit", |s| s.revisit);
+    tui::run(stepper, |s| render(&walls, &first, size, s))
+}
@@ -0,0 +1,102 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use std::collections::HashSet;
+
+use crate::*;
+
+use aoc::generator::{self, Rng};
+
+/// `size` by `size` map with about one wall in 8 cells, and the guard
+/// somewhere its route leaves the map from; the solvers take the size of
+/// the map as a parameter
+pub struct Gen;
+
+type Map = Vec<Vec<char>>;
+
+impl generator::Generator for Gen {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        loop {
+            let mut map = (0..size)
+                .map(|_| {
+                    (0..size)
+                        .map(|_| if rng.chance(1, 8) { '#' } else { '.' })
+                        .collect()
+                })
+                .collect::<Map>();
+            let (x, y) = (rng.below(size as u64), rng.below(size as u64));
+            map[y as usize][x as usize] = '^';
+            // Part a has no answer when the guard walks in a cycle
+            if route(&map, None).is_some() {
+                return map
+                    .iter()
+                    .map(|line| line.iter().collect::<String>() + "\n")
+                    .collect();
+            }
+        }
+    }
+}
+
+/// Positions visited by the guard, with `extra` as a wall, or None if
+/// the guard walks in a cycle
+fn route(map: &Map, extra: Option<(usize, usize)>) -> Option<HashSet<(usize, usize)>> {
+    let size = map.len();
+    let mut pos = (0..size)
+        .flat_map(|y| (0..size).map(move |x| (x, y)))
+        .find(|&(x, y)| map[y][x] == '^')?;
+    let mut dir = (0_isize, -1_isize);
+    let mut seen = HashSet::new();
+    while seen.insert((pos, dir)) {
+        let next = (
+            pos.0.checked_add_signed(dir.0),
+            pos.1.checked_add_signed(dir.1),
+        );
+        let (Some(x), Some(y)) = next else {
+            return Some(seen.into_iter().map(|(pos, _)| pos).collect());
+        };
+        if x >= size || y >= size {
+            return Some(seen.into_iter().map(|(pos, _)| pos).collect());
+        }
+        if map[y][x] == '#' || extra == Some((x, y)) {
+            dir = (-dir.1, dir.0);
+        } else {
+            pos = (x, y);
+        }
+    }
+    None
+}
+
+fn parse_map(input: &str) -> Map {
+    input.lines().map(|line| line.chars().collect()).collect()
+}
+
+/// Walk the route, remembering every position and direction
+pub fn reference_a(input: &str) -> Result<usize> {
+    let map = parse_map(input);
+    Ok(route(&map, None).map_or(0, |visited| visited.len()))
+}
+
+/// Try a new wall in every empty cell
+pub fn reference_b(input: &str) -> Result<usize> {
+    let map = parse_map(input);
+    let size = map.len();
+    Ok((0..size)
+        .flat_map(|y| (0..size).map(move |x| (x, y)))
+        .filter(|&(x, y)| map[y][x] == '.' && route(&map, Some((x, y))).is_none())
+        .count())
+}
+
+/// Side of the generated map
+#[cfg(test)]
+fn side(input: &str) -> u16 {
+    input.lines().count() as u16
+}
+
+generator_tests!(
+    Gen,
+    12,
+    100,
+    a: |i: &str| a::process(i.as_bytes(), side(i)) => reference_a,
+    b: |i: &str| b::process(i.as_bytes(), side(i)) => reference_b,
+);
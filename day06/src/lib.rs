@@ -3,20 +3,29 @@
 // file 'LICENSE', which is part of this source code package.
 
 pub use aoc::*;
+use serde::{Deserialize, Serialize};
 
-pub const EXAMPLE: &str = "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...
-";
-
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub mod a;
+pub mod b;
+pub mod generator;
+
+solutions! {
+    day: 6,
+    'a' => |bufin| a::process(bufin, 130),
+    'b' => |bufin| b::process(bufin, 130),
+}
+
+dumper! {
+    day: 6,
+    parser::parse0,
+    parser::unparse,
+}
+
+pub const GENERATOR: &dyn aoc::generator::Generator = &generator::Gen;
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Cell {
     None,
     #[default]
@@ -25,10 +34,69 @@ pub enum Cell {
 
 pub type Sqrid = sqrid::sqrid_create!(130, 130, false);
 pub type Pos = sqrid::pos_create!(Sqrid);
+pub use sqrid::Dir;
+
+/// Next position and direction of the guard, who turns right when there
+/// is a wall ahead; None when the guard leaves the `width` by `height` map
+pub fn guard_step(
+    (width, height): (u16, u16),
+    is_wall: impl Fn(Pos) -> bool,
+    (guard, d): (Pos, Dir),
+) -> Option<(Pos, Dir)> {
+    let next = (guard + d)
+        .ok()
+        .filter(|n| n.x() < width && n.y() < height)?;
+    Some(if is_wall(next) {
+        (guard, d + Dir::E)
+    } else {
+        (next, d)
+    })
+}
+
+visual! {
+    day: 6,
+    title: "Route of the guard",
+    |bufin| render(bufin),
+}
+
+/// The walls and the route of the guard until it leaves the map
+pub fn render(bufin: impl BufRead) -> Result<svg::Canvas> {
+    let input = std::io::read_to_string(bufin)?;
+    let grid_raw = parser::parse0(input.as_bytes())?;
+    let (width, height) = (grid_raw[0].len(), grid_raw.len());
+    let (walls, guard) = parser::parse(input.as_bytes())?;
+    let mut canvas = svg::Canvas::new(width, height, "#0f0f23");
+    let paint = |canvas: &mut svg::Canvas, pos: Pos, color| {
+        canvas.paint(pos.x() as usize, pos.y() as usize, color)
+    };
+    for wall in walls.iter() {
+        paint(&mut canvas, wall, "#666677");
+    }
+    let mut state = (guard, Dir::N);
+    while let Some(next) = guard_step((width as u16, height as u16), |p| walls.contains(&p), state)
+    {
+        paint(&mut canvas, next.0, "#00cc00");
+        state = next;
+    }
+    paint(&mut canvas, guard, "#ffff66");
+    Ok(canvas)
+}
+
+validator! {
+    day: 6,
+    |bufin| Ok(validate(&parser::parse0(bufin)?)),
+}
+
+/// The map must be rectangular and have exactly one guard
+pub fn validate(grid_raw: &[Vec<(Cell, bool)>]) -> validate::Violations {
+    let mut violations = validate::Violations::default();
+    violations.expect_rectangular(grid_raw);
+    violations.expect_one_cell("guard", grid_raw, |(_, guard)| *guard);
+    violations
+}
 
 pub mod parser {
     use aoc::parser::*;
-    use std::collections::HashSet;
 
     use super::*;
 
@@ -55,10 +123,14 @@ pub mod parser {
         aoc::parse_with!(multi::many1(line), bufin)
     }
 
-    pub fn parse(bufin: impl BufRead) -> Result<(HashSet<Pos>, Pos)> {
-        let Ok(grid_raw) = parse0(bufin) else {
-            panic!();
-        };
+    fn pos_from((x, y): (usize, usize)) -> Result<Pos> {
+        Ok(Pos::new(x as u16, y as u16)
+            .map_err(|_| PuzzleError::unsupported(format!("grid too large at {},{}", x, y)))?)
+    }
+
+    pub fn parse(bufin: impl BufRead) -> Result<(PosSet<Pos>, Pos)> {
+        let grid_raw = parse0(bufin)?;
+        validate(&grid_raw).into_result()?;
         let guard = grid_raw
             .iter()
             .enumerate()
@@ -68,23 +140,28 @@ pub mod parser {
                     .map(move |(x, (_, guard))| (guard, (x, y)))
             })
             .find_map(|(g, xy)| g.then_some(xy))
-            .map(|(x, y)| Pos::new_unwrap(x as u16, y as u16))
-            .unwrap();
+            .ok_or_else(|| PuzzleError::invalid_input("no guard found"))?;
+        let guard = pos_from(guard)?;
         let walls = grid_raw
             .iter()
             .enumerate()
             .flat_map(|(y, line)| {
-                line.iter().enumerate().filter_map(move |(x, (cell, _))| {
-                    if *cell == Cell::Wall {
-                        Some(Pos::new_unwrap(x as u16, y as u16))
-                    } else {
-                        None
-                    }
-                })
+                line.iter()
+                    .enumerate()
+                    .filter_map(move |(x, (cell, _))| (*cell == Cell::Wall).then_some((x, y)))
             })
-            .collect::<HashSet<_>>();
+            .map(pos_from)
+            .collect::<Result<PosSet<_>>>()?;
         Ok((walls, guard))
     }
+
+    pub fn unparse(grid: &[Vec<(Cell, bool)>]) -> String {
+        aoc::dump::grid_text(grid, |cell| match cell {
+            (_, true) => '^',
+            (Cell::Wall, _) => '#',
+            _ => '.',
+        })
+    }
 }
 
 #[test]
@@ -94,3 +171,27 @@ fn test() -> Result<()> {
     assert_eq!(input.1.tuple(), (4, 6));
     Ok(())
 }
+
+#[test]
+fn test_validate() -> Result<()> {
+    let grid_raw = parser::parse0("^.#\n.^.\n".as_bytes())?;
+    let violations = validate(&grid_raw);
+    assert_eq!(violations.len(), 1);
+    assert_eq!(
+        violations.0[0].location,
+        Some(Location { line: 2, column: 2 })
+    );
+    assert!(parser::parse("...\n.#.\n".as_bytes()).is_err());
+    assert!(validate(&parser::parse0(EXAMPLE.as_bytes())?).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_render() -> Result<()> {
+    let canvas = render(".#...\n...^.\n".as_bytes())?;
+    assert_eq!((canvas.width, canvas.height), (5, 2));
+    assert_eq!(canvas.get(1, 0), Some("#666677"));
+    assert_eq!(canvas.get(3, 0), Some("#00cc00"));
+    assert_eq!(canvas.get(3, 1), Some("#ffff66"));
+    Ok(())
+}
@@ -0,0 +1,74 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use aoc::generator::{self, Rng};
+
+/// `size` equations of up to 8 operands, half of them solvable with
+/// random operators
+pub struct Gen;
+
+fn concat(a: N, b: N) -> N {
+    format!("{}{}", a, b).parse().unwrap()
+}
+
+impl generator::Generator for Gen {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        (0..size)
+            .map(|_| {
+                let len = rng.between(2, 8) as usize;
+                let operands = (0..len).map(|_| rng.between(1, 99)).collect::<Vec<_>>();
+                let result = if rng.chance(1, 2) {
+                    operands[1..]
+                        .iter()
+                        .fold(operands[0], |acc, &o| match rng.below(3) {
+                            0 => acc + o,
+                            1 => acc * o,
+                            _ => concat(acc, o),
+                        })
+                } else {
+                    rng.between(1, 10000)
+                };
+                let operands = operands.iter().map(|o| o.to_string()).collect::<Vec<_>>();
+                format!("{}: {}\n", result, operands.join(" "))
+            })
+            .collect()
+    }
+}
+
+/// Evaluate every combination of operators, left to right
+fn reference(input: &str, concat_op: bool) -> Result<N> {
+    let nops: u32 = if concat_op { 3 } else { 2 };
+    let equations = parser::parse(input.as_bytes())?;
+    Ok(equations
+        .into_iter()
+        .filter(|(result, operands)| {
+            let combinations = nops.pow(operands.len() as u32 - 1);
+            (0..combinations).any(|mut ops| {
+                let value = operands[1..].iter().try_fold(operands[0], |acc, &o| {
+                    let op = ops % nops;
+                    ops /= nops;
+                    match op {
+                        0 => acc.checked_add(o),
+                        1 => acc.checked_mul(o),
+                        _ => format!("{}{}", acc, o).parse().ok(),
+                    }
+                });
+                value == Some(*result)
+            })
+        })
+        .map(|(result, _)| result)
+        .sum())
+}
+
+pub fn reference_a(input: &str) -> Result<N> {
+    reference(input, false)
+}
+
+pub fn reference_b(input: &str) -> Result<N> {
+    reference(input, true)
+}
+
+generator_tests!(Gen, 20, 100, a => reference_a, b => reference_b);
@@ -4,16 +4,25 @@
 
 pub use aoc::*;
 
-pub const EXAMPLE: &str = "190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20
-";
+pub mod a;
+pub mod b;
+pub mod generator;
+
+solutions! {
+    day: 7,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 7,
+    parser::parse,
+    parser::unparse,
+}
+
+pub const GENERATOR: &dyn aoc::generator::Generator = &generator::Gen;
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub type N = u64;
 pub type Equation = (N, Vec<N>);
@@ -38,6 +47,16 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Equation>> {
         aoc::parse_with!(multi::many1(line), bufin)
     }
+
+    pub fn unparse(equations: &[Equation]) -> String {
+        equations
+            .iter()
+            .map(|(result, operands)| {
+                let operands = operands.iter().map(|o| o.to_string()).collect::<Vec<_>>();
+                format!("{}: {}\n", result, operands.join(" "))
+            })
+            .collect()
+    }
 }
 
 #[test]
@@ -0,0 +1,50 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::*;
+
+pub fn process(bufin: impl BufRead) -> Result<usize> {
+    let input = parser::parse(bufin)?;
+    let size = V2::new(input[0].len() as i32, input.len() as i32);
+    Ok(input
+        .into_iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.into_iter()
+                .enumerate()
+                .filter(|(_, cell)| *cell != Cell::Empty)
+                .map(move |(x, cell)| (cell, V2::new(x as i32, y as i32)))
+        })
+        .fold(
+            HashMap::<Cell, Vec<V2<i32>>>::default(),
+            |mut ants, (cell, pos)| {
+                ants.entry(cell).or_default().push(pos);
+                ants
+            },
+        )
+        .into_values()
+        .flat_map(|xys| {
+            xys.iter()
+                .flat_map(|&xy1| {
+                    xys.iter()
+                        .flat_map(move |&xy2| {
+                            if xy1 == xy2 {
+                                vec![]
+                            } else {
+                                let d = xy2 - xy1;
+                                vec![xy2 + d, xy1 - d]
+                            }
+                        })
+                        .filter(|xy| xy.inside(size))
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<HashSet<_>>()
+        .len())
+}
+
+example_tests!("a", process);
@@ -3,22 +3,26 @@
 // file 'LICENSE', which is part of this source code package.
 
 pub use aoc::*;
+use serde::{Deserialize, Serialize};
 
-pub const EXAMPLE: &str = "............
-........0...
-.....0......
-.......0....
-....0.......
-......A.....
-............
-............
-........A...
-.........A..
-............
-............
-";
-
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 8,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 8,
+    parser::parse,
+    parser::unparse,
+}
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Cell {
     Empty,
     Ant(char),
@@ -37,6 +41,13 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<Cell>>> {
         aoc::parse_with!(grid(cell), bufin)
     }
+
+    pub fn unparse(grid: &[Vec<Cell>]) -> String {
+        aoc::dump::grid_text(grid, |cell| match cell {
+            Cell::Empty => '.',
+            Cell::Ant(c) => *c,
+        })
+    }
 }
 
 #[test]
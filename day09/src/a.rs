@@ -0,0 +1,45 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn solve(input: &[usize], mut fblocks: FileBlocks) -> usize {
+    let mut r = 0_usize;
+    let mut block = 0;
+    for (i, nblocks) in input.iter().copied().enumerate() {
+        if i % 2 == 0 {
+            // File, with the blocks that were not moved yet
+            let id = i / 2;
+            let Some(left) = fblocks.remove(&id) else {
+                // Moved entirely, so were all the next ones; we are done
+                break;
+            };
+            for b in block..(block + left) {
+                r += b * id;
+            }
+        } else {
+            // Empty, bring from last id
+            for b in block..(block + nblocks) {
+                let Some(mut e) = fblocks.last_entry() else {
+                    break;
+                };
+                r += b * *e.key();
+                *e.get_mut() -= 1;
+                if *e.get() == 0 {
+                    e.remove();
+                }
+            }
+        }
+        block += nblocks;
+    }
+    r
+}
+
+pub fn process(bufin: impl BufRead) -> Result<usize> {
+    let input = parser::parse(bufin)?;
+    let fblocks = file_blocks(&input);
+    Ok(solve(&input, fblocks))
+}
+
+example_tests!("a", process);
@@ -0,0 +1,78 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use aoc::generator::{self, Rng};
+
+/// Disk map with `size` digits, alternating files of 1 to 9 blocks and
+/// free spaces of 0 to 9
+pub struct Gen;
+
+impl generator::Generator for Gen {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let mut map = (0..size.max(1))
+            .map(|i| {
+                let digit = if i % 2 == 0 {
+                    rng.between(1, 9)
+                } else {
+                    rng.between(0, 9)
+                };
+                char::from_digit(digit as u32, 10).unwrap()
+            })
+            .collect::<String>();
+        map.push('\n');
+        map
+    }
+}
+
+/// Block by block layout of the disk, with the file IDs
+fn layout(map: &[usize]) -> Vec<Option<usize>> {
+    map.iter()
+        .enumerate()
+        .flat_map(|(i, &len)| std::iter::repeat_n((i % 2 == 0).then_some(i / 2), len))
+        .collect()
+}
+
+fn checksum(disk: &[Option<usize>]) -> usize {
+    disk.iter()
+        .enumerate()
+        .map(|(b, id)| b * id.unwrap_or(0))
+        .sum()
+}
+
+/// Move the last block to the first free one until there's no gap
+pub fn reference_a(input: &str) -> Result<usize> {
+    let mut disk = layout(&parser::parse(input.as_bytes())?);
+    while let (Some(free), Some(last)) = (
+        disk.iter().position(Option::is_none),
+        disk.iter().rposition(Option::is_some),
+    ) {
+        if last < free {
+            break;
+        }
+        disk.swap(free, last);
+    }
+    Ok(checksum(&disk))
+}
+
+/// Move each whole file, from the highest ID, to the first span of free
+/// blocks on its left that fits it
+pub fn reference_b(input: &str) -> Result<usize> {
+    let map = parser::parse(input.as_bytes())?;
+    let mut disk = layout(&map);
+    for id in (0..map.len().div_ceil(2)).rev() {
+        let start = disk.iter().position(|b| *b == Some(id)).unwrap();
+        let len = map[id * 2];
+        let Some(dest) = (0..start).find(|&d| disk[d..d + len].iter().all(Option::is_none)) else {
+            continue;
+        };
+        for i in 0..len {
+            disk.swap(dest + i, start + i);
+        }
+    }
+    Ok(checksum(&disk))
+}
+
+generator_tests!(Gen, 20, 200, a => reference_a, b => reference_b);
@@ -2,9 +2,55 @@
 // This file is subject to the terms and conditions defined in
 // file 'LICENSE', which is part of this source code package.
 
+use std::collections::BTreeMap;
+
 pub use aoc::*;
 
-pub const EXAMPLE: &str = "2333133121414131402\n";
+pub mod a;
+pub mod b;
+pub mod generator;
+
+solutions! {
+    day: 9,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+both! {
+    day: 9,
+    |bufin, phases| both(bufin, phases),
+}
+
+dumper! {
+    day: 9,
+    parser::parse,
+    parser::unparse,
+}
+
+/// Both parts, sharing the parsed disk map and the table of files
+pub fn both(bufin: impl BufRead, phases: &mut Phases) -> Result<(usize, usize)> {
+    let input = parser::parse(bufin)?;
+    let fblocks = file_blocks(&input);
+    phases.lap("parse");
+    let checksum_a = a::solve(&input, fblocks.clone());
+    phases.lap("a");
+    let checksum_b = b::solve(&input, fblocks);
+    phases.lap("b");
+    Ok((checksum_a, checksum_b))
+}
+
+pub const GENERATOR: &dyn aoc::generator::Generator = &generator::Gen;
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+/// Number of blocks of each file, by ID
+pub type FileBlocks = BTreeMap<usize, usize>;
+
+pub fn file_blocks(input: &[usize]) -> FileBlocks {
+    (0..input.len().div_ceil(2))
+        .map(|id| (id, input[id * 2]))
+        .collect()
+}
 
 pub mod parser {
     use aoc::parser::*;
@@ -25,6 +71,11 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<usize>> {
         aoc::parse_with!(line, bufin)
     }
+
+    pub fn unparse(digits: &[usize]) -> String {
+        let digits = digits.iter().map(|d| d.to_string()).collect::<String>();
+        format!("{}\n", digits)
+    }
 }
 
 #[test]
@@ -33,3 +84,11 @@ fn test() -> Result<()> {
     assert_eq!(input.len(), 19);
     Ok(())
 }
+
+#[test]
+fn test_both() -> Result<()> {
+    let mut phases = Phases::default();
+    assert_eq!(both(EXAMPLE.as_bytes(), &mut phases)?, (1928, 2858));
+    assert_eq!(phases.laps.len(), 3);
+    Ok(())
+}
@@ -4,15 +4,22 @@
 
 pub use aoc::*;
 
-pub const EXAMPLE: &str = "89010123
-78121874
-87430965
-96549874
-45678903
-32019012
-01329801
-10456732
-";
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 10,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 10,
+    parser::parse,
+    parser::unparse,
+}
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub type Sqrid = sqrid::sqrid_create!(45, 45, false);
 pub type Pos = sqrid::pos_create!(Sqrid);
@@ -25,6 +32,10 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<u8>>> {
         aoc::parse_with!(grid(digit1), bufin)
     }
+
+    pub fn unparse(grid: &[Vec<u8>]) -> String {
+        aoc::dump::grid_text(grid, |height| *height)
+    }
 }
 
 #[test]
@@ -0,0 +1,12 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(num: usize, bufin: impl BufRead) -> Result<usize> {
+    let input = parser::parse(bufin)?;
+    Ok(solve(num, &input))
+}
+
+example_tests!("a", |bufin, params| process(params.get("blinks")?, bufin));
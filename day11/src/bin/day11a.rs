@@ -4,23 +4,6 @@
 
 use day11::*;
 
-fn process(num: usize, bufin: impl BufRead) -> Result<usize> {
-    let input = parser::parse(bufin)?;
-    Ok(solve(num, &input))
-}
-
-#[test]
-fn test1() -> Result<()> {
-    assert_eq!(process(6, "125 17\n".as_bytes())?, 22);
-    Ok(())
-}
-
-#[test]
-fn test2() -> Result<()> {
-    assert_eq!(process(25, "125 17\n".as_bytes())?, 55312);
-    Ok(())
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(25, stdin().lock()))
+    do_main(|| a::process(25, stdin().lock()))
 }
@@ -4,11 +4,6 @@
 
 use day11::*;
 
-fn process(num: usize, bufin: impl BufRead) -> Result<usize> {
-    let input = parser::parse(bufin)?;
-    Ok(solve(num, &input))
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(75, stdin().lock()))
+    do_main(|| b::process(75, stdin().lock()))
 }
@@ -0,0 +1,51 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use aoc::generator::{self, Rng};
+
+/// `size` stones, with from 1 to 7 digits
+pub struct Gen;
+
+impl generator::Generator for Gen {
+    fn generate(&self, rng: &mut Rng, size: usize) -> String {
+        let stones = (0..size.max(1))
+            .map(|_| {
+                let digits = rng.between(1, 7) as u32;
+                rng.between(0, 10_u64.pow(digits) - 1).to_string()
+            })
+            .collect::<Vec<_>>();
+        format!("{}\n", stones.join(" "))
+    }
+}
+
+/// Actually blink, keeping every stone
+pub fn reference(num: usize, input: &str) -> Result<usize> {
+    let mut stones = parser::parse(input.as_bytes())?;
+    for _ in 0..num {
+        stones = stones
+            .into_iter()
+            .flat_map(|stone| {
+                let s = stone.to_string();
+                if stone == 0 {
+                    vec![1]
+                } else if s.len() % 2 == 0 {
+                    let (left, right) = s.split_at(s.len() / 2);
+                    vec![left.parse().unwrap(), right.parse().unwrap()]
+                } else {
+                    vec![stone * 2024]
+                }
+            })
+            .collect();
+    }
+    Ok(stones.len())
+}
+
+generator_tests!(
+    Gen,
+    5,
+    50,
+    blinks: |i: &str| a::process(12, i.as_bytes()) => |i: &str| reference(12, i),
+);
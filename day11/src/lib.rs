@@ -6,7 +6,25 @@ use cached::proc_macro::cached;
 
 pub use aoc::*;
 
-pub const EXAMPLE: &str = "0 1 10 99 999\n";
+pub mod a;
+pub mod b;
+pub mod generator;
+
+solutions! {
+    day: 11,
+    'a' => |bufin| a::process(25, bufin),
+    'b' => |bufin| b::process(75, bufin),
+}
+
+dumper! {
+    day: 11,
+    parser::parse,
+    parser::unparse,
+}
+
+pub const GENERATOR: &dyn aoc::generator::Generator = &generator::Gen;
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub type Stone = u64;
 
@@ -24,6 +42,11 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Stone>> {
         aoc::parse_with!(line, bufin)
     }
+
+    pub fn unparse(stones: &[Stone]) -> String {
+        let stones = stones.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        format!("{}\n", stones.join(" "))
+    }
 }
 
 #[test]
@@ -0,0 +1,27 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(bufin: impl BufRead) -> Result<usize> {
+    let input = parser::parse(bufin)?;
+    let regions = regions(input)?;
+    // Calculate each area, perimeter and use them to calculate the final result.
+    Ok(regions
+        .into_iter()
+        .map(|coords| {
+            let area = coords.len();
+            let perimeter: usize = coords
+                .iter()
+                .flat_map(|pos| {
+                    let coords = &coords;
+                    Dir::iter::<false>().map(move |d| usize::from(!neighbor_in(coords, pos, d)))
+                })
+                .sum();
+            area * perimeter
+        })
+        .sum())
+}
+
+example_tests!("a", process);
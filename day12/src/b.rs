@@ -0,0 +1,43 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(bufin: impl BufRead) -> Result<usize> {
+    let input = parser::parse(bufin)?;
+    let regions = regions(input)?;
+    // Plots already counted in a side facing each direction
+    let mut visited = PosDirSet::<Pos>::new();
+    // Calculate each area and sides and use them to calculate the final result.
+    Ok(regions
+        .into_iter()
+        .map(|coords| {
+            let area = coords.len();
+            let mut sides = 0;
+            for pos in coords.iter() {
+                for d in Dir::iter::<false>() {
+                    if visited.contains(&pos, d) || neighbor_in(&coords, pos, d) {
+                        continue;
+                    }
+                    sides += 1;
+                    // Walk along the side both ways
+                    for turn in [Dir::E, Dir::W] {
+                        let rund = d + turn;
+                        let mut inside = pos;
+                        while let Some(next) = (inside + rund).ok().filter(|p| coords.contains(p)) {
+                            if neighbor_in(&coords, next, d) {
+                                break;
+                            }
+                            visited.insert(next, d);
+                            inside = next;
+                        }
+                    }
+                }
+            }
+            area * sides
+        })
+        .sum())
+}
+
+example_tests!("b", process);
@@ -2,38 +2,45 @@
 // This file is subject to the terms and conditions defined in
 // file 'LICENSE', which is part of this source code package.
 
+use std::collections::HashMap;
+
 pub use aoc::*;
 
-pub const EXAMPLE1: &str = "AAAA
-BBCD
-BBCC
-EEEC
-";
-
-pub const EXAMPLE2: &str = "OOOOO
-OXOXO
-OOOOO
-OXOXO
-OOOOO
-";
-
-pub const EXAMPLE3: &str = "EEEEE
-EXXXX
-EEEEE
-EXXXX
-EEEEE
-";
-
-pub const EXAMPLE4: &str = "AAAAAA
-AAABBA
-AAABBA
-ABBAAA
-ABBAAA
-AAAAAA
-";
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 12,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 12,
+    parser::parse,
+    parser::unparse,
+}
+
+pub const EXAMPLE1: &str = include_str!("../examples/example1.txt");
+
+pub const EXAMPLE2: &str = include_str!("../examples/example2.txt");
+
+pub const EXAMPLE3: &str = include_str!("../examples/example3.txt");
+
+pub const EXAMPLE4: &str = include_str!("../examples/example4.txt");
 
 pub type Cell = char;
 
+pub type Sqrid = sqrid::sqrid_create!(139, 139, false);
+pub type Pos = sqrid::pos_create!(Sqrid);
+pub use sqrid::Dir;
+pub type Grid = sqrid::grid_create!(Sqrid, Option<Cell>);
+
+/// Whether the plot next to `pos` in `dir` is in the region
+pub fn neighbor_in(region: &PosSet<Pos>, pos: Pos, dir: Dir) -> bool {
+    (pos + dir).is_ok_and(|n| region.contains(&n))
+}
+
 pub mod parser {
     use aoc::parser::*;
 
@@ -52,6 +59,31 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<char>>> {
         aoc::parse_with!(multi::many1(line), bufin)
     }
+
+    pub fn unparse(grid: &[Vec<Cell>]) -> String {
+        aoc::dump::grid_text(grid, |c| *c)
+    }
+}
+
+/// Plots of each region: the 4-connected ones with the same plant
+pub fn regions(input: Vec<Vec<Cell>>) -> Result<Vec<PosSet<Pos>>> {
+    let mut grid = Grid::default();
+    grid.extend_from_vecvec(
+        input
+            .into_iter()
+            .map(|line| line.into_iter().map(Some).collect())
+            .collect(),
+    )
+    .map_err(|_| PuzzleError::unsupported("garden larger than 140x140"))?;
+    let mut dsu = dsu::grid_regions(&grid, false, |a, b| a == b);
+    let mut regions = HashMap::<usize, PosSet<Pos>>::default();
+    for (pos, cell) in grid.iter_pos() {
+        if cell.is_some() {
+            let region = dsu.find(usize::from(pos));
+            regions.entry(region).or_default().insert(pos);
+        }
+    }
+    Ok(regions.into_values().collect())
 }
 
 #[test]
@@ -59,5 +91,8 @@ fn test() -> Result<()> {
     let input = parser::parse(EXAMPLE1.as_bytes())?;
     assert_eq!(input.len(), 4);
     assert_eq!(input[0].len(), 4);
+    assert_eq!(regions(input)?.len(), 5);
+    let input = parser::parse(EXAMPLE2.as_bytes())?;
+    assert_eq!(regions(input)?.len(), 5);
     Ok(())
 }
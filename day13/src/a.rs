@@ -0,0 +1,59 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::collections::HashSet;
+
+fn calc(a: XY, b: XY, prize: XY) -> Option<usize> {
+    // Pressing a button costs at most 3
+    let mut frontier = pq::BucketQueue::new(3);
+    frontier.push(0, (0, 0, XY::new(0, 0)));
+    let mut visited = HashSet::new();
+    while let Some((cost, (atimes, btimes, pos))) = frontier.pop() {
+        if pos == prize {
+            return Some(cost);
+        }
+        let key = (cost, pos);
+        if pos.x > prize.x || pos.y > prize.y || visited.contains(&key) {
+            continue;
+        }
+        visited.insert(key);
+        if atimes < 100 {
+            frontier.push(cost + 3, (atimes + 1, btimes, pos + a));
+        }
+        if btimes < 100 {
+            frontier.push(cost + 1, (atimes, btimes + 1, pos + b));
+        }
+    }
+    None
+}
+
+pub fn process(bufin: impl BufRead) -> Result<usize> {
+    let machines = parser::parse(bufin)?;
+    Ok(machines
+        .into_iter()
+        .filter_map(|(a, b, prize)| calc(a, b, prize))
+        .sum())
+}
+
+/// Same as [`process`], but solving the linear system of part b
+pub fn process_linalg(bufin: impl BufRead) -> Result<Num> {
+    let machines = parser::parse(bufin)?;
+    machines.into_iter().try_fold(0, |total, (a, b, prize)| {
+        let cost = match b::presses(a, b, prize)? {
+            Some([apress, bpress]) if apress <= 100 && bpress <= 100 => apress * 3 + bpress,
+            _ => 0,
+        };
+        Ok(total + cost)
+    })
+}
+
+#[test]
+fn test_linalg() -> Result<()> {
+    assert_eq!(process_linalg(EXAMPLE.as_bytes())?, 480);
+    Ok(())
+}
+
+example_tests!("a", process);
@@ -0,0 +1,50 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use aoc::linalg::{self, Solution};
+
+/// Number of presses of each button that gets the claw to the prize
+pub fn presses(a: XY, b: XY, prize: XY) -> Result<Option<[Num; 2]>> {
+    // apress * a + bpress * b = prize, in both coordinates:
+    let system = [vec![a.x, b.x], vec![a.y, b.y]];
+    let solution = linalg::solve(&system, &[prize.x, prize.y])?;
+    if let Solution::Underdetermined { .. } = solution {
+        return Err(PuzzleError::unsupported("buttons move the claw in the same direction").into());
+    }
+    // Presses must be non-negative integers, which Num being unsigned
+    // takes care of:
+    Ok(solution
+        .integral::<Num>()
+        .map(|presses| [presses[0], presses[1]]))
+}
+
+fn calc(a: XY, b: XY, prize: XY) -> Result<Option<Num>> {
+    Ok(presses(a, b, prize)?.map(|[apress, bpress]| apress * 3 + bpress))
+}
+
+pub fn process(add: Num, bufin: impl BufRead) -> Result<Num> {
+    let machines = parser::parse(bufin)?;
+    machines.into_iter().try_fold(0, |total, (a, b, prize)| {
+        Ok(total + calc(a, b, XY::new(add, add) + prize)?.unwrap_or(0))
+    })
+}
+
+#[test]
+fn test2() -> Result<()> {
+    let m = parser::parse(EXAMPLE.as_bytes())?;
+    let add = XY::new(10000000000000, 10000000000000);
+    let m = m
+        .into_iter()
+        .map(|(a, b, prize)| (a, b, add + prize))
+        .collect::<Vec<_>>();
+    assert!(calc(m[0].0, m[0].1, m[0].2)?.is_none());
+    assert!(calc(m[1].0, m[1].1, m[1].2)?.is_some());
+    assert!(calc(m[2].0, m[2].1, m[2].2)?.is_none());
+    assert!(calc(m[3].0, m[3].1, m[3].2)?.is_some());
+    Ok(())
+}
+
+example_tests!("b", |bufin, params| process(params.get("add")?, bufin));
@@ -3,27 +3,27 @@
 // file 'LICENSE', which is part of this source code package.
 
 pub use aoc::*;
-pub use num::complex::Complex;
 
-pub const EXAMPLE: &str = "Button A: X+94, Y+34
-Button B: X+22, Y+67
-Prize: X=8400, Y=5400
+pub mod a;
+pub mod b;
 
-Button A: X+26, Y+66
-Button B: X+67, Y+21
-Prize: X=12748, Y=12176
+solutions! {
+    day: 13,
+    'a' => |bufin| a::process(bufin),
+    'a' "linalg" => |bufin| a::process_linalg(bufin),
+    'b' => |bufin| b::process(10000000000000, bufin),
+}
 
-Button A: X+17, Y+86
-Button B: X+84, Y+37
-Prize: X=7870, Y=6450
+dumper! {
+    day: 13,
+    parser::parse,
+    parser::unparse,
+}
 
-Button A: X+69, Y+23
-Button B: X+27, Y+71
-Prize: X=18641, Y=10279
-";
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub type Num = u64;
-pub type XY = Complex<Num>;
+pub type XY = V2<Num>;
 
 pub mod parser {
     use aoc::parser::*;
@@ -40,7 +40,7 @@ pub mod parser {
         let (input, _) = tag(", Y+")(input)?;
         let (input, y) = num(input)?;
         let (input, _) = character::newline(input)?;
-        Ok((input, Complex::new(x, y)))
+        Ok((input, V2::new(x, y)))
     }
 
     fn button_b(input: &str) -> IResult<&str, XY> {
@@ -49,7 +49,7 @@ pub mod parser {
         let (input, _) = tag(", Y+")(input)?;
         let (input, y) = num(input)?;
         let (input, _) = character::newline(input)?;
-        Ok((input, Complex::new(x, y)))
+        Ok((input, V2::new(x, y)))
     }
 
     fn prize(input: &str) -> IResult<&str, XY> {
@@ -58,7 +58,7 @@ pub mod parser {
         let (input, _) = tag(", Y=")(input)?;
         let (input, y) = num(input)?;
         let (input, _) = character::newline(input)?;
-        Ok((input, Complex::new(x, y)))
+        Ok((input, V2::new(x, y)))
     }
 
     fn entry(input: &str) -> IResult<&str, (XY, XY, XY)> {
@@ -71,6 +71,19 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<(XY, XY, XY)>> {
         aoc::parse_with!(multi::separated_list1(character::newline, entry), bufin)
     }
+
+    pub fn unparse(entries: &[(XY, XY, XY)]) -> String {
+        entries
+            .iter()
+            .map(|(ba, bb, prize)| {
+                format!(
+                    "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                    ba.x, ba.y, bb.x, bb.y, prize.x, prize.y
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
 }
 
 #[test]
@@ -0,0 +1,42 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use std::cmp::Ordering;
+
+use crate::*;
+
+pub fn process(width: i32, height: i32, bufin: impl BufRead) -> Result<usize> {
+    let input = parser::parse(bufin)?;
+    let size = V2::new(width, height);
+    Ok(input
+        .into_iter()
+        .map(|bot| Robot {
+            p: (bot.p + bot.v * 100).wrap(size),
+            v: bot.v,
+        })
+        .fold(vec![0, 0, 0, 0], |mut quads, bot| {
+            match (bot.p.x.cmp(&(width / 2)), bot.p.y.cmp(&(height / 2))) {
+                (Ordering::Less, Ordering::Less) => {
+                    quads[0] += 1;
+                }
+                (Ordering::Greater, Ordering::Less) => {
+                    quads[1] += 1;
+                }
+                (Ordering::Less, Ordering::Greater) => {
+                    quads[2] += 1;
+                }
+                (Ordering::Greater, Ordering::Greater) => {
+                    quads[3] += 1;
+                }
+                (_, _) => {}
+            }
+            quads
+        })
+        .into_iter()
+        .product())
+}
+
+example_tests!("a", |bufin, params| {
+    process(params.get("width")?, params.get("height")?, bufin)
+});
@@ -0,0 +1,97 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+#[cfg(test)]
+fn next_second(width: i32, height: i32, bots: &[Robot]) -> Vec<Robot> {
+    let size = V2::new(width, height);
+    bots.iter()
+        .map(|bot| Robot {
+            p: (bot.p + bot.v).wrap(size),
+            v: bot.v,
+        })
+        .collect()
+}
+
+/// Variance of the coordinates, scaled by n^2 to stay in integers
+fn spread(coords: impl Iterator<Item = i32>) -> i64 {
+    let (n, sum, sumsq) = coords.fold((0_i64, 0_i64, 0_i64), |(n, sum, sumsq), c| {
+        let c = c as i64;
+        (n + 1, sum + c, sumsq + c * c)
+    });
+    n * sumsq - sum * sum
+}
+
+/// Seconds after which a coordinate of all robots repeats
+///
+/// That's the size of the axis, unless every velocity shares a factor
+/// with it; [`cycle::brent`] finds it either way.
+fn period(size: i32, bots: &[Robot], coord: &impl Fn(&Robot) -> (i32, i32)) -> i32 {
+    let (ps, vs): (Vec<i32>, Vec<i32>) = bots.iter().map(coord).unzip();
+    let step = |ps: &Vec<i32>| {
+        let moved = ps.iter().zip(&vs).map(|(p, v)| (p + v).rem_euclid(size));
+        Some(moved.collect::<Vec<_>>())
+    };
+    cycle::brent(ps, step).map_or(size, |cycle| cycle.len as i32)
+}
+
+/// Second in which the coordinate is the most clustered, and the period
+/// after which that repeats
+fn most_clustered(size: i32, bots: &[Robot], coord: impl Fn(&Robot) -> (i32, i32)) -> (i32, i32) {
+    let period = period(size, bots, &coord);
+    let t = (0..period)
+        .min_by_key(|t| {
+            spread(bots.iter().map(|bot| {
+                let (p, v) = coord(bot);
+                (p + t * v).rem_euclid(size)
+            }))
+        })
+        .unwrap_or(0);
+    (t, period)
+}
+
+pub fn process(width: i32, height: i32, bufin: impl BufRead) -> Result<i32> {
+    let bots = parser::parse(bufin)?;
+    // The tree shows up when both coordinates are clustered at the same
+    // time, and each coordinate is periodic: CRT finds it.
+    let x = most_clustered(width, &bots, |bot| (bot.p.x, bot.v.x));
+    let y = most_clustered(height, &bots, |bot| (bot.p.y, bot.v.y));
+    let (found, _period) = math::crt(&[x, y])?;
+    Ok(found)
+}
+
+#[test]
+fn test() -> Result<()> {
+    // Robots that all meet at (5, 3) at second 40:
+    let (width, height, meet) = (11_i32, 7_i32, 40_i32);
+    let input = [(1, 2), (-3, 1), (2, -1), (4, 3), (-2, -3)]
+        .into_iter()
+        .map(|(vx, vy)| {
+            let x = (5 - meet * vx).rem_euclid(width);
+            let y = (3 - meet * vy).rem_euclid(height);
+            format!("p={},{} v={},{}\n", x, y, vx, vy)
+        })
+        .collect::<String>();
+    assert_eq!(process(width, height, input.as_bytes())?, meet);
+    Ok(())
+}
+
+#[test]
+fn test_period() -> Result<()> {
+    // Positions repeat every width * height seconds, as both are prime
+    let bots = parser::parse(EXAMPLE.as_bytes())?;
+    let cycle = cycle::brent(bots, |bots| Some(next_second(11, 7, bots)));
+    assert_eq!(cycle, Some(cycle::Cycle { start: 0, len: 77 }));
+    Ok(())
+}
+
+#[test]
+fn test_period_factor() -> Result<()> {
+    // Even velocities on an axis of 10 repeat every 5 seconds
+    let bots = parser::parse("p=1,0 v=2,1\np=4,0 v=-4,3\n".as_bytes())?;
+    assert_eq!(period(10, &bots, &|bot: &Robot| (bot.p.x, bot.v.x)), 5);
+    assert_eq!(period(10, &bots, &|bot: &Robot| (bot.p.y, bot.v.y)), 10);
+    Ok(())
+}
@@ -6,39 +6,33 @@ use std::collections::HashSet;
 
 use day14::*;
 
-fn disp(width: i32, height: i32, bots: &[Robot]) {
+/// Show the robots after `seconds`, for a look at the tree
+fn disp(width: i32, height: i32, bots: &[Robot], seconds: i32) {
+    let size = V2::new(width, height);
     let bots = bots
         .iter()
-        .map(|bot| (bot.p.0, bot.p.1))
-        .collect::<HashSet<(i32, i32)>>();
+        .map(|bot| (bot.p + bot.v * seconds).wrap(size))
+        .collect::<HashSet<V2<i32>>>();
     for y in 0..height {
         for x in 0..width {
-            print!("{}", if bots.contains(&(x, y)) { "X" } else { " " });
+            eprint!(
+                "{}",
+                if bots.contains(&V2::new(x, y)) {
+                    "X"
+                } else {
+                    " "
+                }
+            );
         }
-        println!();
+        eprintln!();
     }
 }
 
-fn next_second(width: i32, height: i32, bots: &mut [Robot]) {
-    for bot in bots.iter_mut() {
-        let x = (bot.p.0 + bot.v.0).rem_euclid(width);
-        let y = (bot.p.1 + bot.v.1).rem_euclid(height);
-        bot.p = (x, y);
-    }
-}
-
-fn process(width: i32, height: i32, bufin: impl BufRead) -> Result<usize> {
-    // Did this one "manually"
-    let mut bots = parser::parse(bufin)?;
-    let found = 7687;
-    for _ in 0..found {
-        next_second(width, height, &mut bots);
-    }
-    println!("{}", found);
-    disp(width, height, &bots);
-    Ok(0)
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(101, 103, stdin().lock()))
+    let input = std::io::read_to_string(stdin().lock())?;
+    do_main(|| {
+        let found = b::process(101, 103, input.as_bytes())?;
+        disp(101, 103, &parser::parse(input.as_bytes())?, found);
+        Ok(found)
+    })
 }
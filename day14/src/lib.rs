@@ -3,25 +3,29 @@
 // file 'LICENSE', which is part of this source code package.
 
 pub use aoc::*;
+use serde::{Deserialize, Serialize};
 
-pub const EXAMPLE: &str = "p=0,4 v=3,-3
-p=6,3 v=-1,-3
-p=10,3 v=-1,2
-p=2,0 v=2,-1
-p=0,0 v=1,3
-p=3,0 v=-2,-2
-p=7,6 v=-1,-3
-p=3,0 v=-1,-2
-p=9,3 v=2,3
-p=7,3 v=-1,2
-p=2,4 v=2,-3
-p=9,5 v=-3,-3
-";
+pub mod a;
+pub mod b;
 
-#[derive(Debug)]
+solutions! {
+    day: 14,
+    'a' => |bufin| a::process(101, 103, bufin),
+    'b' => |bufin| b::process(101, 103, bufin),
+}
+
+dumper! {
+    day: 14,
+    parser::parse,
+    parser::unparse,
+}
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Robot {
-    pub p: (i32, i32),
-    pub v: (i32, i32),
+    pub p: V2<i32>,
+    pub v: V2<i32>,
 }
 
 pub mod parser {
@@ -46,8 +50,8 @@ pub mod parser {
         Ok((
             input,
             Robot {
-                p: (x, y),
-                v: (vx, vy),
+                p: V2::new(x, y),
+                v: V2::new(vx, vy),
             },
         ))
     }
@@ -55,6 +59,13 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Robot>> {
         aoc::parse_with!(multi::many1(line), bufin)
     }
+
+    pub fn unparse(robots: &[Robot]) -> String {
+        robots
+            .iter()
+            .map(|r| format!("p={},{} v={},{}\n", r.p.x, r.p.y, r.v.x, r.v.y))
+            .collect()
+    }
 }
 
 #[test]
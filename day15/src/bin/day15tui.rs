@@ -0,0 +1,69 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Step through the robot's moves in the wide warehouse of part b
+
+use day15::b::*;
+use day15::*;
+
+struct State {
+    grid: Grid2,
+    moves: usize,
+    moved: bool,
+    pushed: bool,
+}
+
+fn render(width: u16, height: u16, dirs: &[Dir], state: &State) -> String {
+    let mut out = String::new();
+    for y in 0..height {
+        for x in 0..width {
+            out.push_str(&state.grid[Pos2::new_unwrap(x, y)].to_string());
+        }
+        out.push('\n');
+    }
+    let next = dirs
+        .get(state.moves)
+        .map_or("-".to_string(), Dir::to_string);
+    out.push_str(&format!(
+        "move {}/{}, next {}, gps {}\n",
+        state.moves,
+        dirs.len(),
+        next,
+        gps(&state.grid)
+    ));
+    out
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    let (gridvec, dirs) = parser::parse(stdin().lock())?;
+    let height = gridvec.len() as u16;
+    let width = 2 * gridvec.first().map_or(0, |l| l.len()) as u16;
+    let x0 = State {
+        grid: widen(gridvec),
+        moves: 0,
+        moved: false,
+        pushed: false,
+    };
+    let stepper = tui::Stepper::new(x0, |s: &State| {
+        let dir = *dirs.get(s.moves)?;
+        let mut grid = s.grid;
+        let moved = robot_move(&mut grid, dir).ok()?;
+        let robot = grid.iter_pos().find(|(_, c)| **c == Cell2::Robot)?.0;
+        Some(State {
+            pushed: moved && s.grid[robot].is_box(),
+            grid,
+            moves: s.moves + 1,
+            moved,
+        })
+    })
+    .breakpoint("blocked", |s| s.moves > 0 && !s.moved)
+    .breakpoint("vertical push", |s| {
+        s.pushed && matches!(dirs[s.moves - 1], Dir::N | Dir::S)
+    })
+    .breakpoint("horizontal push", |s| {
+        s.pushed && matches!(dirs[s.moves - 1], Dir::E | Dir::W)
+    });
+    tui::run(stepper, |s| render(width, height, &dirs, s))
+}
@@ -3,55 +3,30 @@
 // file 'LICENSE', which is part of this source code package.
 
 pub use aoc::*;
+use serde::{Deserialize, Serialize};
 
-pub const EXAMPLE1: &str = "########
-#..O.O.#
-##@.O..#
-#...O..#
-#.#.O..#
-#...O..#
-#......#
-########
-
-<^^>>>vv
-<v>>v<<
-";
-
-pub const EXAMPLE2: &str = "##########
-#..O..O.O#
-#......O.#
-#.OO..O.O#
-#..O@..O.#
-#O#..O...#
-#O..O..O.#
-#.OO.O.OO#
-#....O...#
-##########
-
-<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
-vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
-><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
-<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
-^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
-^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
->^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
-<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
-^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
-v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
-";
-
-pub const EXAMPLE3: &str = "#######
-#...#.#
-#.....#
-#..OO@#
-#..O..#
-#.....#
-#######
-
-<vv<<^^<<^^
-";
-
-#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 15,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 15,
+    parser::parse0,
+    parser::unparse,
+}
+
+pub const EXAMPLE1: &str = include_str!("../examples/example1.txt");
+
+pub const EXAMPLE2: &str = include_str!("../examples/example2.txt");
+
+pub const EXAMPLE3: &str = include_str!("../examples/example3.txt");
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub enum Cell {
     #[default]
     Wall,
@@ -102,25 +77,43 @@ pub mod parser {
         character::one_of("#.O@")(input).map(|(input, c)| (input, Cell::from(c)))
     }
 
-    fn nl(input: &str) -> IResult<&str, Option<Dir>> {
+    fn nl(input: &str) -> IResult<&str, Option<char>> {
         let (input, _) = tag("\n")(input)?;
         Ok((input, None))
     }
 
-    fn dir(input: &str) -> IResult<&str, Option<Dir>> {
+    fn dir(input: &str) -> IResult<&str, Option<char>> {
         let (input, dirchar) = character::one_of("<>^v")(input)?;
-        Ok((input, Some(Dir::try_from(dirchar).unwrap())))
+        Ok((input, Some(dirchar)))
     }
 
-    fn griddirs(input: &str) -> IResult<&str, (Vec<Vec<Cell>>, Vec<Dir>)> {
+    /// The grid and the moves, as the `<>^v` characters without the
+    /// newlines
+    fn gridmoves(input: &str) -> IResult<&str, (Vec<Vec<Cell>>, String)> {
         let (input, grid) = grid(cell)(input)?;
         let (input, _) = character::newline(input)?;
         let (input, diropts) = multi::many1(branch::alt((dir, nl)))(input)?;
         Ok((input, (grid, diropts.into_iter().flatten().collect())))
     }
 
-    pub fn parse(mut bufin: impl BufRead) -> Result<(Vec<Vec<Cell>>, Vec<Dir>)> {
-        aoc::parse_with!(griddirs, bufin)
+    pub fn parse0(mut bufin: impl BufRead) -> Result<(Vec<Vec<Cell>>, String)> {
+        aoc::parse_with!(gridmoves, bufin)
+    }
+
+    pub fn parse(bufin: impl BufRead) -> Result<(Vec<Vec<Cell>>, Vec<Dir>)> {
+        let (grid, moves) = parse0(bufin)?;
+        let dirs = moves
+            .chars()
+            .map(|c| {
+                Dir::try_from(c)
+                    .map_err(|_| PuzzleError::invalid_input(format!("invalid move {:?}", c)).into())
+            })
+            .collect::<Result<_>>()?;
+        Ok((grid, dirs))
+    }
+
+    pub fn unparse((grid, moves): &(Vec<Vec<Cell>>, String)) -> String {
+        format!("{}\n{}\n", aoc::dump::grid_text(grid, |c| *c), moves)
     }
 }
 
@@ -0,0 +1,12 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(bufin: impl BufRead) -> Result<usize> {
+    let g = parser::parse(bufin)?;
+    calc_best(&g)
+}
+
+example_tests!("a", process);
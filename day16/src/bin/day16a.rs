@@ -4,11 +4,6 @@
 
 use day16::*;
 
-fn process(bufin: impl BufRead) -> Result<usize> {
-    let g = parser::parse(bufin)?;
-    calc_best(&g)
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(stdin().lock()))
+    do_main(|| a::process(stdin().lock()))
 }
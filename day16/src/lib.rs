@@ -3,48 +3,46 @@
 // file 'LICENSE', which is part of this source code package.
 
 pub use aoc::*;
+use serde::{Deserialize, Serialize};
+
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 16,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+both! {
+    day: 16,
+    |bufin, phases| both(bufin, phases),
+}
+
+dumper! {
+    day: 16,
+    parser::parse_,
+    parser::unparse,
+}
+
+/// Both parts, with b reusing the best score found by a
+pub fn both(bufin: impl BufRead, phases: &mut Phases) -> Result<(usize, usize)> {
+    let g = parser::parse(bufin)?;
+    phases.lap("parse");
+    let points = calc_best(&g)?;
+    phases.lap("a");
+    let tiles = b::solve(&g, points)?;
+    phases.lap("b");
+    Ok((points, tiles))
+}
 
-use std::cmp::Reverse;
-use std::collections::BinaryHeap;
 use std::collections::HashSet;
 
-pub const EXAMPLE1: &str = "###############
-#.......#....E#
-#.#.###.#.###.#
-#.....#.#...#.#
-#.###.#####.#.#
-#.#.#.......#.#
-#.#.#####.###.#
-#...........#.#
-###.#.#####.#.#
-#...#.....#.#.#
-#.#.#.###.#.#.#
-#.....#...#.#.#
-#.###.#.#.#.#.#
-#S..#.....#...#
-###############
-";
-
-pub const EXAMPLE2: &str = "#################
-#...#...#...#..E#
-#.#.#.#.#.#.#.#.#
-#.#.#.#...#...#.#
-#.#.#.#.###.#.#.#
-#...#.#.#.....#.#
-#.#.#.#.#.#####.#
-#.#...#.#.#.....#
-#.#.#####.#.###.#
-#.#.#.......#...#
-#.#.###.#####.###
-#.#.#...#.....#.#
-#.#.#.#####.###.#
-#.#.#.........#.#
-#.#.#.#########.#
-#S#.............#
-#################
-";
-
-#[derive(Debug, Default, PartialEq, Eq)]
+pub const EXAMPLE1: &str = include_str!("../examples/example1.txt");
+
+pub const EXAMPLE2: &str = include_str!("../examples/example2.txt");
+
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Cell {
     #[default]
     Wall,
@@ -72,6 +70,48 @@ pub type Pos = sqrid::pos_create!(Sqrid);
 pub type Grid = sqrid::grid_create!(Sqrid, Cell);
 pub use sqrid::Dir;
 
+visual! {
+    day: 16,
+    title: "Tiles of the best paths",
+    |bufin| render(bufin),
+}
+
+/// The maze with the tiles that are part of some best path
+pub fn render(bufin: impl BufRead) -> Result<svg::Canvas> {
+    let input = std::io::read_to_string(bufin)?;
+    let vecvec = parser::parse_(input.as_bytes())?;
+    let g = parser::parse(input.as_bytes())?;
+    let width = vecvec.first().map_or(0, Vec::len);
+    let mut canvas = svg::Canvas::new(width, vecvec.len(), "#0f0f23");
+    for pos in b::best_tiles(&g, calc_best(&g)?)? {
+        canvas.paint(pos.x() as usize, pos.y() as usize, "#00cc00");
+    }
+    for (y, line) in vecvec.iter().enumerate() {
+        for (x, cell) in line.iter().enumerate() {
+            match cell {
+                Cell::Wall => canvas.paint(x, y, "#666677"),
+                Cell::Start | Cell::End => canvas.paint(x, y, "#ffff66"),
+                Cell::Empty => {}
+            }
+        }
+    }
+    Ok(canvas)
+}
+
+validator! {
+    day: 16,
+    |bufin| Ok(validate(&parser::parse_(bufin)?)),
+}
+
+/// The maze must be rectangular and have exactly one start and one end
+pub fn validate(vecvec: &[Vec<Cell>]) -> validate::Violations {
+    let mut violations = validate::Violations::default();
+    violations.expect_rectangular(vecvec);
+    violations.expect_one_cell("start", vecvec, |c| *c == Cell::Start);
+    violations.expect_one_cell("end", vecvec, |c| *c == Cell::End);
+    violations
+}
+
 pub mod parser {
     use aoc::parser::*;
 
@@ -87,10 +127,20 @@ pub mod parser {
 
     pub fn parse(bufin: impl BufRead) -> Result<Grid> {
         let vecvec = parse_(bufin)?;
+        validate(&vecvec).into_result()?;
         let mut g = Grid::default();
         g.extend_from_vecvec(vecvec)?;
         Ok(g)
     }
+
+    pub fn unparse(grid: &[Vec<Cell>]) -> String {
+        aoc::dump::grid_text(grid, |cell| match cell {
+            Cell::Wall => '#',
+            Cell::Empty => '.',
+            Cell::Start => 'S',
+            Cell::End => 'E',
+        })
+    }
 }
 
 #[test]
@@ -107,10 +157,18 @@ fn test_a2() -> Result<()> {
     Ok(())
 }
 
-pub fn grid_find(g: &Grid, cell: Cell) -> Pos {
-    g.iter_pos()
+#[test]
+fn test_both() -> Result<()> {
+    let mut phases = Phases::default();
+    assert_eq!(both(EXAMPLE2.as_bytes(), &mut phases)?, (11048, 64));
+    assert_eq!(phases.laps.len(), 3);
+    Ok(())
+}
+
+pub fn grid_find(g: &Grid, cell: Cell) -> Result<Pos> {
+    Ok(g.iter_pos()
         .find_map(|(p, c)| (*c == cell).then_some(p))
-        .unwrap()
+        .ok_or_else(|| PuzzleError::invalid_input(format!("no {:?} cell found", cell)))?)
 }
 
 pub fn go(g: &Grid, p: Pos, d: Dir) -> Option<Pos> {
@@ -118,14 +176,14 @@ pub fn go(g: &Grid, p: Pos, d: Dir) -> Option<Pos> {
 }
 
 pub fn calc_best(g: &Grid) -> Result<usize> {
-    let mut frontier = BinaryHeap::<(Reverse<usize>, Pos, Dir)>::new();
-    let pos0 = grid_find(g, Cell::Start);
+    let mut frontier = pq::BucketQueue::new(1001);
+    let pos0 = grid_find(g, Cell::Start)?;
     let dir0 = Dir::E;
-    frontier.push((Reverse(0), pos0, dir0));
+    frontier.push(0, (pos0, dir0));
     let mut visited = HashSet::new();
-    while let Some((points0, pos, dir)) = frontier.pop() {
+    while let Some((points0, (pos, dir))) = frontier.pop() {
         if g[pos] == Cell::End {
-            return Ok(points0.0);
+            return Ok(points0);
         }
         let key = (pos, dir);
         if visited.contains(&key) {
@@ -135,10 +193,17 @@ pub fn calc_best(g: &Grid) -> Result<usize> {
         for turn in [Dir::N, Dir::E, Dir::W] {
             let d = dir + turn;
             if let Some(p) = go(g, pos, d) {
-                let points = Reverse(points0.0 + 1 + if turn == Dir::N { 0 } else { 1000 });
-                frontier.push((points, p, d));
+                let points = points0 + 1 + if turn == Dir::N { 0 } else { 1000 };
+                frontier.push(points, (p, d));
             }
         }
     }
-    Err(eyre!("path not found"))
+    Err(PuzzleError::no_solution("path not found").into())
+}
+
+#[test]
+fn test_validate() -> Result<()> {
+    assert!(parser::parse("#####\n#S..#\n#.S.#\n#####\n".as_bytes()).is_err());
+    assert!(validate(&parser::parse_(EXAMPLE1.as_bytes())?).is_empty());
+    Ok(())
 }
@@ -0,0 +1,13 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(bufin: impl BufRead) -> Result<String> {
+    let mut cpu = parser::parse(bufin)?;
+    cpu.run();
+    Ok(cpu.output_str())
+}
+
+example_tests!("a", process);
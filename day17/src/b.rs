@@ -0,0 +1,105 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::*;
+
+pub const EXAMPLE_FIXPOINT: &str = include_str!("../examples/example_fixpoint.txt");
+
+fn distance(cpu: &Computer) -> usize {
+    (0..(std::cmp::max(cpu.prog_vec.len(), cpu.output.len())))
+        .map(|i| {
+            if i > cpu.output.len() || cpu.output.len() != cpu.prog_vec.len() {
+                128
+            } else {
+                let o = cpu.output[i] as usize;
+                let p = cpu.prog_vec[i] as usize;
+                o.abs_diff(p)
+            }
+        })
+        .sum()
+}
+
+pub type Node = [u8; 16];
+
+fn node_to_a(n: Node) -> u64 {
+    n.into_iter()
+        .enumerate()
+        .map(|(i, v)| {
+            let v = v as u64;
+            v << ((n.len() - i - 1) * 3)
+        })
+        .sum()
+}
+
+fn pushit(frontier: &mut BinaryHeap<(Reverse<usize>, Node)>, cpu: &Computer, n: Node) {
+    let a = node_to_a(n);
+    let mut c = cpu.clone();
+    c.regs[Reg::A] = a;
+    c.run();
+    let dist = distance(&c);
+    frontier.push((Reverse(dist), n));
+}
+
+/// State of [`find_fixpoint`] saved in its checkpoints
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Search {
+    pub frontier: Vec<(Reverse<usize>, Node)>,
+    pub visited: Vec<Node>,
+}
+
+fn find_fixpoint(cpu: Computer) -> Result<Num> {
+    let mut checkpoint = checkpoint::Checkpoint::<Search>::new("day17b", 1, &cpu)?;
+    let (mut frontier, mut visited) = match checkpoint.resume()? {
+        Some(search) => (
+            BinaryHeap::from(search.frontier),
+            search.visited.into_iter().collect::<HashSet<Node>>(),
+        ),
+        None => {
+            let mut frontier = BinaryHeap::<(Reverse<usize>, Node)>::new();
+            pushit(&mut frontier, &cpu, Node::default());
+            (frontier, HashSet::<Node>::new())
+        }
+    };
+    while let Some((Reverse(dist), node)) = frontier.pop() {
+        if dist == 0 {
+            checkpoint.done()?;
+            return Ok(node_to_a(node));
+        }
+        if visited.contains(&node) {
+            continue;
+        }
+        visited.insert(node);
+        for i in 0..node.len() {
+            if node[i] > 0 {
+                let mut n = node;
+                n[i] -= 1;
+                pushit(&mut frontier, &cpu, n);
+            }
+            if node[i] < 7 {
+                let mut n = node;
+                n[i] += 1;
+                pushit(&mut frontier, &cpu, n);
+            }
+        }
+        checkpoint.tick(|| Search {
+            frontier: frontier.iter().copied().collect(),
+            visited: visited.iter().copied().collect(),
+        })?;
+    }
+    checkpoint.done()?;
+    Err(PuzzleError::no_solution("could not find answer").into())
+}
+
+pub fn process(bufin: impl BufRead) -> Result<Num> {
+    let cpu = parser::parse(bufin)?;
+    find_fixpoint(cpu)
+}
+
+example_tests!("b", process);
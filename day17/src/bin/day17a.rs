@@ -4,18 +4,6 @@
 
 use day17::*;
 
-fn process(bufin: impl BufRead) -> Result<String> {
-    let mut cpu = parser::parse(bufin)?;
-    cpu.run();
-    Ok(cpu.output_str())
-}
-
-#[test]
-fn test() -> Result<()> {
-    assert_eq!(process(EXAMPLE.as_bytes())?, "4,6,3,5,6,3,5,2,1,0");
-    Ok(())
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(stdin().lock()))
+    do_main(|| a::process(stdin().lock()))
 }
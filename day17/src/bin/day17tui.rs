@@ -0,0 +1,44 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+//! Step through the program; the optional argument overrides register A
+
+use day17::*;
+
+fn render(cpu: &Computer) -> String {
+    let mut out = String::new();
+    for (name, value) in [("A", cpu.regs.a), ("B", cpu.regs.b), ("C", cpu.regs.c)] {
+        out.push_str(&format!("{} = {:>20} = 0o{:o}\n", name, value, value));
+    }
+    out.push('\n');
+    for (i, (instr, op)) in cpu.prog.iter().enumerate() {
+        let mark = if 2 * i == cpu.ip { "->" } else { "  " };
+        out.push_str(&format!("{} {:3} {:?} {:?}\n", mark, 2 * i, instr, op));
+    }
+    out.push_str(&format!("\noutput: {}\n", cpu.output_str()));
+    out
+}
+
+fn current(cpu: &Computer) -> Option<Instruction> {
+    cpu.prog.get(cpu.ip / 2).map(|op| op.0)
+}
+
+fn main() -> Result<()> {
+    color_eyre::install()?;
+    let mut cpu = parser::parse(stdin().lock())?;
+    if let Some(a) = std::env::args().nth(1) {
+        cpu.regs.a = a.parse()?;
+    }
+    let stepper = tui::Stepper::new(cpu, |cpu: &Computer| {
+        if cpu.halted() {
+            return None;
+        }
+        let mut next = cpu.clone();
+        next.once();
+        Some(next)
+    })
+    .breakpoint("out", |cpu| current(cpu) == Some(Instruction::Out))
+    .breakpoint("jnz", |cpu| current(cpu) == Some(Instruction::Jnz));
+    tui::run(stepper, render)
+}
@@ -3,17 +3,28 @@
 // file 'LICENSE', which is part of this source code package.
 
 pub use aoc::*;
+use serde::{Deserialize, Serialize};
 
-pub const EXAMPLE: &str = "Register A: 729
-Register B: 0
-Register C: 0
+pub mod a;
+pub mod b;
 
-Program: 0,1,5,4,3,0
-";
+solutions! {
+    day: 17,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 17,
+    parser::parse,
+    parser::unparse,
+}
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub type Num = u64;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default, Serialize, Deserialize)]
 pub struct Registers {
     pub a: Num,
     pub b: Num,
@@ -41,7 +52,7 @@ impl std::ops::IndexMut<Reg> for Registers {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub enum Instruction {
     Adv,
     Bxl,
@@ -82,38 +93,38 @@ impl TryFrom<u8> for Instruction {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub enum Reg {
     A,
     B,
     C,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub enum Operand {
     Value(Num),
     Reg(Reg),
 }
 
 impl Operand {
-    pub fn new_instr(instr: &Instruction, v: u8) -> Self {
+    pub fn new_instr(instr: &Instruction, v: u8) -> Result<Self, String> {
         if instr.is_combo() {
             Operand::new_combo(v)
         } else {
-            Operand::new_literal(v)
+            Ok(Operand::new_literal(v))
         }
     }
     pub fn new_literal(v: u8) -> Self {
         Operand::Value(v as Num)
     }
-    pub fn new_combo(v: u8) -> Self {
+    pub fn new_combo(v: u8) -> Result<Self, String> {
         match v {
-            0..=3 => Operand::Value(v as Num),
-            4 => Operand::Reg(Reg::A),
-            5 => Operand::Reg(Reg::B),
-            6 => Operand::Reg(Reg::C),
-            7 => panic!("reserved operand {}", v),
-            _ => panic!("invalid operand {}", v),
+            0..=3 => Ok(Operand::Value(v as Num)),
+            4 => Ok(Operand::Reg(Reg::A)),
+            5 => Ok(Operand::Reg(Reg::B)),
+            6 => Ok(Operand::Reg(Reg::C)),
+            7 => Err(format!("reserved operand {}", v)),
+            _ => Err(format!("invalid operand {}", v)),
         }
     }
 }
@@ -121,7 +132,7 @@ impl Operand {
 pub type Operation = (Instruction, Operand);
 pub type Program = Vec<Operation>;
 
-#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[derive(Debug, PartialEq, Eq, Clone, Default, Serialize, Deserialize)]
 pub struct Computer {
     pub regs: Registers,
     pub prog: Program,
@@ -148,7 +159,7 @@ impl Computer {
     }
 
     pub fn once(&mut self) {
-        assert!(self.ip % 2 == 0);
+        assert!(self.ip.is_multiple_of(2));
         let (instr, opcode) = self.prog[self.ip / 2];
         self.ip += 2;
         let op = self.get(&opcode);
@@ -188,10 +199,6 @@ impl Computer {
 
     pub fn run(&mut self) {
         while !self.halted() {
-            // eprintln!("regs {:?}", self.regs);
-            // let (instr, opcode) = self.prog[self.ip / 2];
-            // let op = self.get(&opcode);
-            // eprintln!("  instr {:?} op {:?}", instr, op);
             self.once();
         }
     }
@@ -285,9 +292,10 @@ pub mod parser {
     fn operation(input: &str) -> IResult<&str, (Operation, (u8, u8))> {
         let (input, instr_u8) = digit1_one_of("01234567")(input)?;
         let (input, _) = tag(",")(input)?;
-        let (input, op_u8) = digit1_one_of("01234567")(input)?;
         let instruction = Instruction::try_from(instr_u8).unwrap();
-        let operand = Operand::new_instr(&instruction, op_u8);
+        let (input, (operand, op_u8)) = combinator::map_res(digit1_one_of("01234567"), |op_u8| {
+            Operand::new_instr(&instruction, op_u8).map(|operand| (operand, op_u8))
+        })(input)?;
         Ok((input, ((instruction, operand), (instr_u8, op_u8))))
     }
 
@@ -322,6 +330,22 @@ pub mod parser {
     pub fn parse_program(mut bufin: impl BufRead) -> Result<Program> {
         aoc::parse_with!(program_contents, bufin).map(|(a, _)| a)
     }
+
+    pub fn unparse(computer: &Computer) -> String {
+        let regs = &computer.regs;
+        let prog = computer
+            .prog_vec
+            .iter()
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>();
+        format!(
+            "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+            regs.a,
+            regs.b,
+            regs.c,
+            prog.join(",")
+        )
+    }
 }
 
 #[test]
@@ -0,0 +1,61 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use std::collections::HashMap;
+
+use crate::*;
+
+use aoc::dsu::Dsu;
+use sqrid::postrait::PosT;
+
+/// Bytes that fell on each position
+type Fallen = HashMap<Pos, usize>;
+
+fn free(fallen: &Fallen, size: u16, p: &Pos) -> bool {
+    p.x() < size && p.y() < size && !fallen.contains_key(p)
+}
+
+/// Join the free position `p` with its free neighbors
+fn connect(dsu: &mut Dsu, fallen: &Fallen, size: u16, p: Pos) {
+    for d in Dir::iter::<false>() {
+        if let Ok(q) = p + d {
+            if free(fallen, size, &q) {
+                dsu.union(usize::from(p), usize::from(q));
+            }
+        }
+    }
+}
+
+pub fn process(size: u16, bufin: impl BufRead) -> Result<Pos> {
+    let input = parser::parse(bufin)?;
+    let mut fallen = Fallen::default();
+    for &p in &input {
+        *fallen.entry(p).or_default() += 1;
+    }
+    let mut dsu = Dsu::new(Pos::SIZE);
+    for p in Pos::iter().filter(|p| free(&fallen, size, p)) {
+        connect(&mut dsu, &fallen, size, p);
+    }
+    let start = usize::from(Pos::TOP_LEFT);
+    let target = usize::from(Pos::new_unwrap(size - 1, size - 1));
+    if dsu.same(start, target) {
+        return Err(PuzzleError::no_solution("path never blocked").into());
+    }
+    // Take the bytes back, last one first, until the path opens again:
+    for p in input.into_iter().rev() {
+        let count = fallen.get_mut(&p).expect("byte must have fallen");
+        *count -= 1;
+        if *count > 0 {
+            continue;
+        }
+        fallen.remove(&p);
+        connect(&mut dsu, &fallen, size, p);
+        if dsu.same(start, target) {
+            return Ok(p);
+        }
+    }
+    Err(PuzzleError::no_solution("path blocked from the start").into())
+}
+
+example_tests!("b", |bufin, params| process(params.get("size")?, bufin));
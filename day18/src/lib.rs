@@ -4,36 +4,28 @@
 
 pub use aoc::*;
 
-pub const EXAMPLE: &str = "5,4
-4,2
-4,5
-3,0
-2,1
-6,3
-2,4
-1,5
-0,6
-3,3
-2,6
-5,1
-1,2
-5,5
-2,5
-6,5
-1,4
-0,4
-6,4
-1,1
-6,1
-1,0
-0,5
-1,6
-2,0
-";
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 18,
+    'a' => |bufin| a::process(1024, 71, bufin),
+    'b' => |bufin| b::process(71, bufin),
+}
+
+dumper! {
+    day: 18,
+    parser::parse0,
+    parser::unparse,
+}
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub type Sqrid = sqrid::sqrid_create!(70, 70, false);
 // pub type Sqrid = sqrid::sqrid_create!(6, 6, false);
 pub type Pos = sqrid::pos_create!(Sqrid);
+// The lint fires inside the expansion of sqrid's gridbool_create!
+#[allow(clippy::manual_div_ceil)]
 pub type Gridbool = sqrid::gridbool_create!(Sqrid);
 pub type Dir = sqrid::Dir;
 
@@ -42,17 +34,43 @@ pub mod parser {
 
     use super::*;
 
-    fn line(input: &str) -> IResult<&str, Pos> {
+    fn line(input: &str) -> IResult<&str, (u16, u16)> {
         let (input, x) = character::u16(input)?;
         let (input, _) = tag(",")(input)?;
         let (input, y) = character::u16(input)?;
         let (input, _) = character::newline(input)?;
-        Ok((input, Pos::new_unwrap(x, y)))
+        Ok((input, (x, y)))
     }
 
-    pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Pos>> {
+    pub fn parse0(mut bufin: impl BufRead) -> Result<Vec<(u16, u16)>> {
         aoc::parse_with!(multi::many1(line), bufin)
     }
+
+    pub fn parse(bufin: impl BufRead) -> Result<Vec<Pos>> {
+        parse0(bufin)?
+            .into_iter()
+            .enumerate()
+            .map(|(i, (x, y))| {
+                Pos::new(x, y).map_err(|_| {
+                    PuzzleError::invalid_input_at(
+                        Location {
+                            line: i + 1,
+                            column: 1,
+                        },
+                        format!("{},{} is out of the grid", x, y),
+                    )
+                    .into()
+                })
+            })
+            .collect()
+    }
+
+    pub fn unparse(bytes: &[(u16, u16)]) -> String {
+        bytes
+            .iter()
+            .map(|(x, y)| format!("{},{}\n", x, y))
+            .collect()
+    }
 }
 
 #[test]
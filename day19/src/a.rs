@@ -0,0 +1,37 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use cached::proc_macro::cached;
+use cached::SizedCache;
+use rayon::prelude::*;
+
+#[cached(
+    ty = "SizedCache<String, bool>",
+    create = "{ SizedCache::with_size(200) }",
+    convert = r#"{ format!("{:?}~{:?}", towels, design) }"#
+)]
+fn possible(towels: &[Towel], design: &[Color]) -> bool {
+    if design.is_empty() {
+        return true;
+    }
+    for t in towels {
+        let tl = t.len();
+        if tl <= design.len() && &design[0..tl] == t.as_slice() && possible(towels, &design[tl..]) {
+            return true;
+        }
+    }
+    false
+}
+
+pub fn process(bufin: impl BufRead) -> Result<usize> {
+    let (towels, designs) = parser::parse(bufin)?;
+    Ok(designs
+        .into_par_iter()
+        .filter(|design| possible(&towels, design))
+        .count())
+}
+
+example_tests!("a", process);
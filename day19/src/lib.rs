@@ -3,20 +3,26 @@
 // file 'LICENSE', which is part of this source code package.
 
 pub use aoc::*;
+use serde::{Deserialize, Serialize};
 
-pub const EXAMPLE: &str = "r, wr, b, g, bwu, rb, gb, br
+pub mod a;
+pub mod b;
 
-brwrr
-bggr
-gbbr
-rrbgbr
-ubwu
-bwurrg
-brgr
-bbrgwb
-";
+solutions! {
+    day: 19,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 19,
+    parser::parse,
+    parser::unparse,
+}
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Color {
     W,
     U,
@@ -39,6 +45,18 @@ impl TryFrom<char> for Color {
     }
 }
 
+impl From<Color> for char {
+    fn from(color: Color) -> char {
+        match color {
+            Color::W => 'w',
+            Color::U => 'u',
+            Color::B => 'b',
+            Color::R => 'r',
+            Color::G => 'g',
+        }
+    }
+}
+
 pub type Towel = Vec<Color>;
 
 pub type Design = Vec<Color>;
@@ -75,6 +93,17 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<(Vec<Towel>, Vec<Design>)> {
         aoc::parse_with!(all, bufin)
     }
+
+    pub fn unparse((towels, designs): &(Vec<Towel>, Vec<Design>)) -> String {
+        let colors =
+            |colors: &Vec<Color>| colors.iter().map(|c| char::from(*c)).collect::<String>();
+        let towels = towels.iter().map(colors).collect::<Vec<_>>();
+        let designs = designs
+            .iter()
+            .map(|design| format!("{}\n", colors(design)))
+            .collect::<String>();
+        format!("{}\n\n{}", towels.join(", "), designs)
+    }
 }
 
 #[test]
@@ -0,0 +1,215 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+// use rayon::prelude::*;
+
+use std::collections::HashMap;
+
+use sqrid::postrait::PosT;
+
+pub type Cost = usize;
+pub type CostMap = sqrid::grid_create!(Sqrid, Cost);
+
+pub fn costmap_calc(g: &Grid, end: Pos) -> Box<CostMap> {
+    let mut frontier = pq::BucketQueue::new(1);
+    frontier.push(0, end);
+    // Boxed, as it's too large for the stack of the test threads
+    let mut costmap = Box::new(CostMap::repeat(Cost::MAX));
+    costmap[end] = 0;
+    while let Some((_, pos0)) = frontier.pop() {
+        let newcost = costmap[pos0] + 1;
+        for dir in Dir::iter::<false>() {
+            let Some(pos) = (pos0 + dir).ok().filter(|p| g[p] != Cell::Wall) else {
+                continue;
+            };
+            let oldcost = costmap[pos];
+            if newcost < oldcost {
+                costmap[pos] = newcost;
+                frontier.push(newcost, pos);
+            }
+        }
+    }
+    costmap
+}
+
+type CheatsMap = HashMap<(Pos, Pos), Cost>;
+
+pub fn cheats_pos_calc(
+    _g: &Grid,
+    maxcheat: usize,
+    cost_to_end_map: &CostMap,
+    cost_so_far: Cost,
+    cheatstart: Pos,
+    cheatsmap: &mut CheatsMap,
+) {
+    let radius = maxcheat as u16;
+    let y0 = cheatstart.y().saturating_sub(radius);
+    let y1 = cheatstart.y().saturating_add(radius);
+    let x0 = cheatstart.x().saturating_sub(radius);
+    let x1 = cheatstart.x().saturating_add(radius);
+    for y in y0..=y1 {
+        for x in x0..=x1 {
+            let Ok(pos) = Pos::new(x, y) else {
+                continue;
+            };
+            if cost_to_end_map[pos] == Cost::MAX {
+                continue;
+            }
+            let dist = Pos::manhattan(&cheatstart, &pos);
+            if dist > maxcheat {
+                continue;
+            }
+            let newcost = cost_so_far + dist as Cost + cost_to_end_map[pos];
+            let e = cheatsmap.entry((cheatstart, pos)).or_insert(Cost::MAX);
+            if newcost < *e {
+                *e = newcost;
+            }
+        }
+    }
+}
+
+/// Cost of the path from every position to the start and to the end
+pub struct Costmaps {
+    pub start: Pos,
+    pub to_start: Box<CostMap>,
+    pub to_end: Box<CostMap>,
+}
+
+pub fn costmaps_calc(g: &Grid) -> Result<Costmaps> {
+    let start = grid_find(g, Cell::Start)?;
+    let end = grid_find(g, Cell::End)?;
+    Ok(Costmaps {
+        start,
+        to_start: costmap_calc(g, start),
+        to_end: costmap_calc(g, end),
+    })
+}
+
+pub fn cheats_calc(g: &Grid, costmaps: &Costmaps, maxcheat: usize) -> HashMap<Cost, usize> {
+    let mut cheatsmap = CheatsMap::default();
+    for (pos, poscost) in costmaps.to_start.iter_pos() {
+        if *poscost == Cost::MAX {
+            continue;
+        }
+        cheats_pos_calc(g, maxcheat, &costmaps.to_end, *poscost, pos, &mut cheatsmap);
+    }
+    let base = costmaps.to_end[costmaps.start];
+    cheatsmap
+        .into_iter()
+        .fold(Default::default(), |mut costfreq, ((_, _), cost)| {
+            if base > cost {
+                let save = base - cost;
+                let e = costfreq.entry(save).or_default();
+                *e += 1;
+            }
+            costfreq
+        })
+}
+
+pub fn cheats_all_calc(g: &Grid, maxcheat: usize) -> Result<HashMap<Cost, usize>> {
+    Ok(cheats_calc(g, &costmaps_calc(g)?, maxcheat))
+}
+
+/// Number of cheats of up to `maxcheat` picoseconds that save at least
+/// `minsave`
+pub fn cheats_count(g: &Grid, costmaps: &Costmaps, maxcheat: usize, minsave: usize) -> usize {
+    cheats_calc(g, costmaps, maxcheat)
+        .into_iter()
+        .filter_map(|(save, count)| (save >= minsave).then_some(count))
+        .sum()
+}
+
+/// Number of cheats of up to `maxcheat` picoseconds that save at least
+/// `minsave`, by the position where they start
+pub fn cheats_by_start(
+    g: &Grid,
+    costmaps: &Costmaps,
+    maxcheat: usize,
+    minsave: usize,
+) -> HashMap<Pos, usize> {
+    let base = costmaps.to_end[costmaps.start];
+    let mut starts = HashMap::default();
+    for (pos, poscost) in costmaps.to_start.iter_pos() {
+        if *poscost == Cost::MAX {
+            continue;
+        }
+        let mut cheatsmap = CheatsMap::default();
+        cheats_pos_calc(g, maxcheat, &costmaps.to_end, *poscost, pos, &mut cheatsmap);
+        let count = cheatsmap
+            .values()
+            .filter(|&&cost| base > cost && base - cost >= minsave)
+            .count();
+        if count > 0 {
+            starts.insert(pos, count);
+        }
+    }
+    starts
+}
+
+/// Same as [`cheats_count`], parsing the input
+pub fn process_cheats(maxcheat: usize, minsave: usize, bufin: impl BufRead) -> Result<usize> {
+    let g = parser::parse(bufin)?;
+    Ok(cheats_count(&g, &costmaps_calc(&g)?, maxcheat, minsave))
+}
+
+pub fn process(minsave: usize, bufin: impl BufRead) -> Result<usize> {
+    process_cheats(20, minsave, bufin)
+}
+
+#[test]
+fn test_cost_to_end_map() -> Result<()> {
+    let g = parser::parse(EXAMPLE.as_bytes())?;
+    assert_eq!(g.into_iter().filter(|c| c != &Cell::Wall).count(), 85);
+    let end = grid_find(&g, Cell::End)?;
+    let cost_to_end_map = costmap_calc(&g, end);
+    let start = grid_find(&g, Cell::Start)?;
+    assert_eq!(cost_to_end_map[start], 84);
+    Ok(())
+}
+
+#[test]
+fn test_cheat_all() -> Result<()> {
+    let g = parser::parse(EXAMPLE.as_bytes())?;
+    let cheats = cheats_all_calc(&g, 20)?;
+    assert_eq!(cheats[&50], 32);
+    assert_eq!(cheats[&52], 31);
+    assert_eq!(cheats[&54], 29);
+    assert_eq!(cheats[&56], 39);
+    assert_eq!(cheats[&58], 25);
+    assert_eq!(cheats[&60], 23);
+    assert_eq!(cheats[&62], 20);
+    assert_eq!(cheats[&64], 19);
+    assert_eq!(cheats[&66], 12);
+    assert_eq!(cheats[&68], 14);
+    assert_eq!(cheats[&70], 12);
+    assert_eq!(cheats[&72], 22);
+    assert_eq!(cheats[&74], 4);
+    assert_eq!(cheats[&76], 3);
+    Ok(())
+}
+
+#[test]
+fn test_cheats2() -> Result<()> {
+    // The cheats of part a, see a::test_cheat*
+    let g = parser::parse(EXAMPLE.as_bytes())?;
+    let cheats = cheats_all_calc(&g, 2)?;
+    assert_eq!(cheats[&12], 3);
+    assert_eq!(cheats[&64], 1);
+    assert_eq!(process_cheats(2, 38, EXAMPLE.as_bytes())?, 3);
+    Ok(())
+}
+
+#[test]
+fn test_cheats_by_start() -> Result<()> {
+    let g = parser::parse(EXAMPLE.as_bytes())?;
+    let costmaps = costmaps_calc(&g)?;
+    let starts = cheats_by_start(&g, &costmaps, 20, 50);
+    assert_eq!(starts.values().sum::<usize>(), 285);
+    assert!(starts.keys().all(|pos| g[pos] != Cell::Wall));
+    Ok(())
+}
+
+example_tests!("b", |bufin, params| process(params.get("minsave")?, bufin));
@@ -3,25 +3,44 @@
 // file 'LICENSE', which is part of this source code package.
 
 pub use aoc::*;
+use serde::{Deserialize, Serialize};
 
-pub const EXAMPLE: &str = "###############
-#...#...#.....#
-#.#.#.#.#.###.#
-#S#...#.#.#...#
-#######.#.#.###
-#######.#.#...#
-#######.#.###.#
-###..E#...#...#
-###.#######.###
-#...###...#...#
-#.#####.#.###.#
-#.#...#.#.#...#
-#.#.#.#.#.#.###
-#...#...#...###
-###############
-";
-
-#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 20,
+    'a' => |bufin| a::process(bufin),
+    'a' "costmap" => |bufin| b::process_cheats(2, 100, bufin),
+    'b' => |bufin| b::process(100, bufin),
+}
+
+both! {
+    day: 20,
+    |bufin, phases| both(100, bufin, phases),
+}
+
+dumper! {
+    day: 20,
+    parser::parse_,
+    parser::unparse,
+}
+
+/// Both parts, sharing the costs from the start and to the end
+pub fn both(minsave: usize, bufin: impl BufRead, phases: &mut Phases) -> Result<(usize, usize)> {
+    let g = parser::parse(bufin)?;
+    phases.lap("parse");
+    let costmaps = b::costmaps_calc(&g)?;
+    let cheats2 = b::cheats_count(&g, &costmaps, 2, minsave);
+    phases.lap("a");
+    let cheats20 = b::cheats_count(&g, &costmaps, 20, minsave);
+    phases.lap("b");
+    Ok((cheats2, cheats20))
+}
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
+
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
 pub enum Cell {
     #[default]
     Wall,
@@ -62,9 +81,54 @@ pub type Sqrid = sqrid::sqrid_create!(142, 142, false);
 //pub type Sqrid = sqrid::sqrid_create!(14, 14, false);
 pub type Pos = sqrid::pos_create!(Sqrid);
 pub type Grid = sqrid::grid_create!(Sqrid, Cell);
+// The lint fires inside the expansion of sqrid's gridbool_create!
+#[allow(clippy::manual_div_ceil)]
 pub type Gridbool = sqrid::gridbool_create!(Sqrid);
 pub use sqrid::Dir;
 
+visual! {
+    day: 20,
+    title: "Where the cheats that save 100ps start",
+    |bufin| render(100, bufin),
+}
+
+/// Heatmap of the number of cheats of part b that save at least
+/// `minsave` by where they start
+pub fn render(minsave: usize, bufin: impl BufRead) -> Result<svg::Canvas> {
+    let input = std::io::read_to_string(bufin)?;
+    let vecvec = parser::parse_(input.as_bytes())?;
+    let g = parser::parse(input.as_bytes())?;
+    let width = vecvec.first().map_or(0, Vec::len);
+    let mut canvas = svg::Canvas::new(width, vecvec.len(), "#0f0f23");
+    for (y, line) in vecvec.iter().enumerate() {
+        for (x, cell) in line.iter().enumerate() {
+            if *cell == Cell::Wall {
+                canvas.paint(x, y, "#666677");
+            }
+        }
+    }
+    let starts = b::cheats_by_start(&g, &b::costmaps_calc(&g)?, 20, minsave);
+    let max = starts.values().copied().max().unwrap_or(0);
+    for (pos, count) in starts {
+        canvas.paint(pos.x() as usize, pos.y() as usize, &svg::heat(count, max));
+    }
+    Ok(canvas)
+}
+
+validator! {
+    day: 20,
+    |bufin| Ok(validate(&parser::parse_(bufin)?)),
+}
+
+/// The maze must be rectangular and have exactly one start and one end
+pub fn validate(vecvec: &[Vec<Cell>]) -> validate::Violations {
+    let mut violations = validate::Violations::default();
+    violations.expect_rectangular(vecvec);
+    violations.expect_one_cell("start", vecvec, |c| *c == Cell::Start);
+    violations.expect_one_cell("end", vecvec, |c| *c == Cell::End);
+    violations
+}
+
 pub mod parser {
     use aoc::parser::*;
 
@@ -80,16 +144,21 @@ pub mod parser {
 
     pub fn parse(bufin: impl BufRead) -> Result<Grid> {
         let vecvec = parse_(bufin)?;
+        validate(&vecvec).into_result()?;
         let mut g = Grid::default();
         g.extend_from_vecvec(vecvec)?;
         Ok(g)
     }
+
+    pub fn unparse(grid: &[Vec<Cell>]) -> String {
+        aoc::dump::grid_text(grid, |cell| cell.to_string())
+    }
 }
 
-pub fn grid_find(g: &Grid, cell: Cell) -> Pos {
-    g.iter_pos()
+pub fn grid_find(g: &Grid, cell: Cell) -> Result<Pos> {
+    Ok(g.iter_pos()
         .find_map(|(p, c)| (*c == cell).then_some(p))
-        .unwrap()
+        .ok_or_else(|| PuzzleError::invalid_input(format!("no {:?} cell found", cell)))?)
 }
 
 pub fn go(g: &Grid, p: Pos, d: Dir) -> Option<Pos> {
@@ -99,7 +168,22 @@ pub fn go(g: &Grid, p: Pos, d: Dir) -> Option<Pos> {
 #[test]
 fn test() -> Result<()> {
     let g = parser::parse(EXAMPLE.as_bytes())?;
-    assert_eq!(grid_find(&g, Cell::Start), Pos::new_static::<1, 3>());
-    assert_eq!(grid_find(&g, Cell::End), Pos::new_static::<5, 7>());
+    assert_eq!(grid_find(&g, Cell::Start)?, Pos::new_static::<1, 3>());
+    assert_eq!(grid_find(&g, Cell::End)?, Pos::new_static::<5, 7>());
+    Ok(())
+}
+
+#[test]
+fn test_both() -> Result<()> {
+    let mut phases = Phases::default();
+    assert_eq!(both(50, EXAMPLE.as_bytes(), &mut phases)?, (1, 285));
+    assert_eq!(phases.laps.len(), 3);
+    Ok(())
+}
+
+#[test]
+fn test_validate() -> Result<()> {
+    assert!(parser::parse("#####\n#S.E#\n#E..#\n#####\n".as_bytes()).is_err());
+    assert!(validate(&parser::parse_(EXAMPLE.as_bytes())?).is_empty());
     Ok(())
 }
@@ -4,18 +4,6 @@
 
 use day21::*;
 
-fn process(bufin: impl BufRead) -> Result<usize> {
-    let input = parser::parse(bufin)?;
-    Ok(input
-        .into_iter()
-        .map(|seq| {
-            let fullseqlen = numpad_sequence_len(25, &seq);
-            let numericpart = numericpart_calc(&seq);
-            fullseqlen * numericpart
-        })
-        .sum())
-}
-
 fn main() -> Result<()> {
-    do_main(|| process(stdin().lock()))
+    do_main(|| b::process(stdin().lock()))
 }
@@ -2,6 +2,7 @@
 // This file is subject to the terms and conditions defined in
 // file 'LICENSE', which is part of this source code package.
 
+use serde::{Deserialize, Serialize};
 pub use sqrid::Dir;
 use sqrid::PosT;
 
@@ -9,11 +10,13 @@ use std::sync::OnceLock;
 
 use std::collections::HashMap;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
 pub enum KeyCell {
     #[default]
     Invalid,
-    D(Dir),
+    D(#[serde(with = "aoc::dump::dir")] Dir),
     A,
 }
 
@@ -4,6 +4,21 @@
 
 pub use aoc::*;
 
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 21,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 21,
+    parser::parse,
+    parser::unparse,
+}
+
 use cached::proc_macro::cached;
 use cached::SizedCache;
 
@@ -17,12 +32,7 @@ pub use self::numpad::*;
 
 pub mod parser;
 
-pub const EXAMPLE: &str = "029A
-980A
-179A
-456A
-379A
-";
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub fn numpad_sequence_len(robots: usize, numcells: &[NumCell]) -> usize {
     let numpad = Numpad::default();
@@ -86,7 +96,7 @@ pub fn numericpart_calc(code: &[NumCell]) -> usize {
 #[test]
 fn test_numericpart_calc() {
     assert_eq!(
-        numericpart_calc(&vec![
+        numericpart_calc(&[
             NumCell::Num(0),
             NumCell::Num(2),
             NumCell::Num(9),
@@ -2,6 +2,7 @@
 // This file is subject to the terms and conditions defined in
 // file 'LICENSE', which is part of this source code package.
 
+use serde::{Deserialize, Serialize};
 pub use sqrid::Dir;
 use sqrid::PosT;
 
@@ -11,7 +12,9 @@ use std::collections::HashMap;
 
 pub use super::keypad::*;
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize,
+)]
 pub enum NumCell {
     #[default]
     Invalid,
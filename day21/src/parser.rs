@@ -21,6 +21,10 @@ pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Vec<NumCell>>> {
     aoc::parse_with!(multi::many1(line), bufin)
 }
 
+pub fn unparse(codes: &[Vec<NumCell>]) -> String {
+    aoc::dump::grid_text(codes, |cell| cell.to_string())
+}
+
 fn key(input: &str) -> IResult<&str, KeyCell> {
     let (input, c) = character::one_of("<>^vA")(input)?;
     Ok((input, c.into()))
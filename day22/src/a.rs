@@ -0,0 +1,20 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+pub fn process(bufin: impl BufRead) -> Result<Num> {
+    let input = parser::parse(bufin)?;
+    Ok(input
+        .into_iter()
+        .map(|mut s| {
+            for _ in 0..2000 {
+                s = evolve(s);
+            }
+            s
+        })
+        .sum())
+}
+
+example_tests!("a", process);
@@ -4,17 +4,24 @@
 
 pub use aoc::*;
 
-pub const EXAMPLE1: &str = "1
-10
-100
-2024
-";
-
-pub const EXAMPLE2: &str = "1
-2
-3
-2024
-";
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 22,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 22,
+    parser::parse,
+    parser::unparse,
+}
+
+pub const EXAMPLE1: &str = include_str!("../examples/example1.txt");
+
+pub const EXAMPLE2: &str = include_str!("../examples/example2.txt");
 
 pub type Num = i64;
 
@@ -64,6 +71,10 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Num>> {
         aoc::parse_with!(multi::many1(line), bufin)
     }
+
+    pub fn unparse(nums: &[Num]) -> String {
+        nums.iter().map(|num| format!("{}\n", num)).collect()
+    }
 }
 
 #[test]
@@ -0,0 +1,19 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use aoc::graph::Graph;
+
+pub fn process(bufin: impl BufRead) -> Result<usize> {
+    let connections = parser::parse(bufin)?;
+    let graph = Graph::from_undirected(connections);
+    Ok(graph
+        .triangles()
+        .into_iter()
+        .filter(|cpus| cpus.iter().any(Cpu::prefix_t))
+        .count())
+}
+
+example_tests!("a", process);
@@ -0,0 +1,21 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use aoc::graph::Graph;
+use itertools::Itertools;
+
+pub fn process(bufin: impl BufRead) -> Result<String> {
+    let connections = parser::parse(bufin)?;
+    let graph = Graph::from_undirected(connections);
+    let best = graph
+        .maximal_cliques()
+        .into_iter()
+        .max_by_key(|clique| clique.len())
+        .ok_or_else(|| PuzzleError::no_solution("no connections"))?;
+    Ok(best.into_iter().sorted().map(|cpu| cpu.0).join(","))
+}
+
+example_tests!("b", process);
@@ -3,10 +3,65 @@
 // file 'LICENSE', which is part of this source code package.
 
 pub use aoc::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 23,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(bufin),
+}
+
+dumper! {
+    day: 23,
+    parser::parse,
+    parser::unparse,
+}
+
+anonymizer! {
+    day: 23,
+    labels: |input, rng| {
+        let cpus = parser::parse(input.as_bytes())?
+            .into_iter()
+            .flat_map(|(cpu1, cpu2)| [cpu1, cpu2])
+            .collect::<Vec<_>>();
+        let names = |t: bool| {
+            let cpus = cpus.iter().filter(move |cpu| cpu.prefix_t() == t);
+            cpus.map(|cpu| cpu.0.as_str())
+        };
+        // Part a depends on which names start with t, so those are only
+        // shuffled among themselves
+        let all = ('a'..='z').flat_map(|c1| ('a'..='z').map(move |c2| format!("{}{}", c1, c2)));
+        let mut labels = anonymize::rename(rng, names(true), all.clone().filter(|n| n.starts_with('t')))?;
+        labels.extend(anonymize::rename(rng, names(false), all.filter(|n| !n.starts_with('t')))?);
+        Ok(labels)
+    },
+    expected: |part, _input, answer, labels| Ok(if part == 'a' {
+        answer.to_string()
+    } else {
+        anonymize::relabel_list(answer, ",", labels)
+    }),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub struct Cpu(pub copstr::Str<2>);
 
+impl TryFrom<String> for Cpu {
+    type Error = copstr::ErrorOverflow;
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        name.as_str().try_into().map(Cpu)
+    }
+}
+
+impl From<Cpu> for String {
+    fn from(cpu: Cpu) -> String {
+        cpu.0.to_string()
+    }
+}
+
 impl Cpu {
     pub fn prefix_t(&self) -> bool {
         self.0.as_str().starts_with('t')
@@ -19,39 +74,7 @@ pub fn connect(cpu1: Cpu, cpu2: Cpu) -> Connection {
     (std::cmp::min(cpu1, cpu2), std::cmp::max(cpu1, cpu2))
 }
 
-pub const EXAMPLE: &str = "kh-tc
-qp-kh
-de-cg
-ka-co
-yn-aq
-qp-ub
-cg-tb
-vc-aq
-tb-ka
-wh-tc
-yn-cg
-kh-ub
-ta-co
-de-co
-tc-td
-tb-wq
-wh-td
-ta-ka
-td-qp
-aq-cg
-wq-ub
-ub-vc
-de-ta
-wq-aq
-wq-vc
-wh-yn
-ka-de
-kh-ta
-co-tc
-wh-qp
-tb-vc
-td-yn
-";
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub mod parser {
     use aoc::parser::*;
@@ -59,8 +82,7 @@ pub mod parser {
     use super::*;
 
     fn cpu(input: &str) -> IResult<&str, Cpu> {
-        let (input, name) = character::alpha1(input)?;
-        Ok((input, Cpu(name.try_into().unwrap())))
+        combinator::map_res(character::alpha1, |name: &str| name.try_into().map(Cpu))(input)
     }
 
     fn line(input: &str) -> IResult<&str, Connection> {
@@ -74,6 +96,13 @@ pub mod parser {
     pub fn parse(mut bufin: impl BufRead) -> Result<Vec<Connection>> {
         aoc::parse_with!(multi::many1(line), bufin)
     }
+
+    pub fn unparse(connections: &[Connection]) -> String {
+        connections
+            .iter()
+            .map(|(cpu1, cpu2)| format!("{}-{}\n", cpu1.0, cpu2.0))
+            .collect()
+    }
 }
 
 #[test]
@@ -82,3 +111,9 @@ fn test() -> Result<()> {
     assert_eq!(input.len(), 32);
     Ok(())
 }
+
+#[test]
+fn test_anonymize() -> Result<()> {
+    ANONYMIZER.check_example(SOLUTIONS, EXAMPLE)?;
+    Ok(())
+}
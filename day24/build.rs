@@ -0,0 +1,7 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+fn main() -> aoc::Result<()> {
+    aoc::examples::build()
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use std::collections::HashMap;
+
+use aoc::graph::Graph;
+
+#[derive(Debug, Default)]
+struct Solver {
+    pub values: HashMap<Wire, bool>,
+    pub formulas: HashMap<Wire, Gate>,
+}
+
+impl Solver {
+    pub fn new(init: Vec<(Wire, bool)>, conns: Vec<Connection>) -> Self {
+        let mut solver = Solver::default();
+        for (wire, value) in init {
+            solver.values.insert(wire, value);
+        }
+        for conn in conns {
+            solver.formulas.insert(conn.0, conn.1);
+        }
+        solver
+    }
+
+    /// Calculate the value of all wires, inputs before outputs
+    pub fn solve(&mut self) -> Result<()> {
+        let mut graph = Graph::new();
+        for (&wire, gate) in &self.formulas {
+            for &input in &gate.inputs {
+                graph.add_edge(input, wire);
+            }
+        }
+        for wire in graph.toposort()? {
+            if self.values.contains_key(&wire) {
+                continue;
+            }
+            let Some(formula) = self.formulas.get(&wire) else {
+                return Err(
+                    PuzzleError::invalid_input(format!("no formula found for {}", wire)).into(),
+                );
+            };
+            let [input0, input1] = formula.inputs.map(|input| self.values[&input]);
+            self.values.insert(wire, formula.op.apply(input0, input1));
+        }
+        Ok(())
+    }
+}
+
+pub fn process(bufin: impl BufRead) -> Result<usize> {
+    let (initial, connections) = parser::parse(bufin)?;
+    let mut solver = Solver::new(initial, connections);
+    solver.solve()?;
+    Ok(solver
+        .values
+        .iter()
+        .filter(|(wire, &value)| wire.prefix() == 'z' && value)
+        .filter_map(|(wire, _)| wire.index())
+        .map(|i| 1 << i)
+        .sum())
+}
+
+example_tests!("a", process);
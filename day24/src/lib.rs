@@ -2,14 +2,56 @@
 // This file is subject to the terms and conditions defined in
 // file 'LICENSE', which is part of this source code package.
 
+use std::collections::HashMap;
+
 pub use aoc::*;
+use serde::{Deserialize, Serialize};
+
+pub mod a;
+pub mod b;
+
+solutions! {
+    day: 24,
+    'a' => |bufin| a::process(bufin),
+    'b' => |bufin| b::process(b::SolveAdder::default(), bufin),
+}
+
+dumper! {
+    day: 24,
+    parser::parse0,
+    parser::unparse,
+}
+
+anonymizer! {
+    day: 24,
+    labels: |input, rng| {
+        let (_, connections) = parser::parse(input.as_bytes())?;
+        // The x, y and z wires are numbered, the others are internal
+        let internal = connections
+            .iter()
+            .flat_map(|(output, gate)| [*output, gate.inputs[0], gate.inputs[1]])
+            .filter(|wire| wire.index().is_none())
+            .collect::<Vec<_>>();
+        let letters = || 'a'..='z';
+        let all = letters().flat_map(|c1| {
+            letters().flat_map(move |c2| letters().map(move |c3| format!("{}{}{}", c1, c2, c3)))
+        });
+        anonymize::rename(rng, internal.iter().map(|wire| wire.0.as_str()), all)
+    },
+    expected: |part, _input, answer, labels| Ok(if part == 'a' {
+        answer.to_string()
+    } else {
+        anonymize::relabel_list(answer, ",", labels)
+    }),
+}
 
 pub mod parser;
 pub use parser::EXAMPLE1;
 pub use parser::EXAMPLE2;
 pub use parser::EXAMPLE3;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
 pub struct Wire(pub copstr::Str<3>);
 
 impl Wire {
@@ -36,6 +78,25 @@ impl Wire {
     }
 }
 
+impl std::fmt::Display for Wire {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl TryFrom<String> for Wire {
+    type Error = copstr::ErrorOverflow;
+    fn try_from(name: String) -> Result<Self, Self::Error> {
+        name.as_str().try_into().map(Wire)
+    }
+}
+
+impl From<Wire> for String {
+    fn from(wire: Wire) -> String {
+        wire.0.to_string()
+    }
+}
+
 #[test]
 fn test_wire() {
     for i in 0..99 {
@@ -44,7 +105,7 @@ fn test_wire() {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Operation {
     And,
     Or,
@@ -61,7 +122,7 @@ impl Operation {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Gate {
     pub inputs: [Wire; 2],
     pub op: Operation,
@@ -77,3 +138,96 @@ impl Gate {
 }
 
 pub type Connection = (Wire, Gate);
+
+validator! {
+    day: 24,
+    |bufin| {
+        let (inits, conns) = parser::parse0(bufin)?;
+        Ok(validate(&inits, &conns))
+    },
+}
+
+/// Each wire must be driven exactly once, by an initial value or by a
+/// gate, and the gates can't form a loop
+pub fn validate(inits: &[(Wire, bool)], conns: &[Connection]) -> validate::Violations {
+    let mut violations = validate::Violations::default();
+    let line = |line| Location { line, column: 1 };
+    // The connections come after the initial values and a blank line:
+    let drivers = inits
+        .iter()
+        .enumerate()
+        .map(|(i, (wire, _))| (*wire, line(i + 1)))
+        .chain(
+            conns
+                .iter()
+                .enumerate()
+                .map(|(i, (wire, _))| (*wire, line(inits.len() + 2 + i))),
+        )
+        .collect::<Vec<_>>();
+    let mut driven = HashMap::<Wire, Location>::default();
+    for (wire, location) in &drivers {
+        if let Some(first) = driven.get(wire) {
+            violations.at(
+                *location,
+                format!("wire {} driven again, first at {}", wire, first),
+            );
+        } else {
+            driven.insert(*wire, *location);
+        }
+    }
+    for (i, (_, gate)) in conns.iter().enumerate() {
+        for input in &gate.inputs {
+            if !driven.contains_key(input) {
+                violations.at(
+                    line(inits.len() + 2 + i),
+                    format!("wire {} is not driven", input),
+                );
+            }
+        }
+    }
+    let graph = graph::Graph::from_edges(
+        conns
+            .iter()
+            .flat_map(|(output, gate)| gate.inputs.iter().map(move |input| (*input, *output))),
+    );
+    if let Some(cycle) = graph.find_cycle() {
+        let wires = cycle.iter().map(Wire::to_string).collect::<Vec<_>>();
+        violations.at(
+            driven[&cycle[0]],
+            format!("gates form a loop: {} -> {}", wires.join(" -> "), wires[0]),
+        );
+    }
+    violations
+}
+
+#[test]
+fn test_validate() -> Result<()> {
+    let input = "x00: 1\ny00: 0\n\nx00 AND y00 -> z00\nz00 OR y01 -> x00\n";
+    let (inits, conns) = parser::parse0(input.as_bytes())?;
+    let violations = validate(&inits, &conns)
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        violations,
+        vec![
+            "line 5, column 1: wire x00 driven again, first at line 1, column 1",
+            "line 5, column 1: wire y01 is not driven",
+            "line 1, column 1: gates form a loop: x00 -> z00 -> x00",
+        ]
+    );
+    assert!(parser::parse(input.as_bytes()).is_err());
+    for example in [EXAMPLE1, EXAMPLE2, EXAMPLE3] {
+        let (inits, conns) = parser::parse0(example.as_bytes())?;
+        assert!(validate(&inits, &conns).is_empty());
+    }
+    Ok(())
+}
+
+#[test]
+fn test_anonymize() -> Result<()> {
+    // The example isn't an adder, so part b doesn't apply
+    let anonymized = ANONYMIZER.check_example(&SOLUTIONS[..1], EXAMPLE2)?;
+    assert!(anonymized.contains("x00: 1\n"));
+    Ok(())
+}
@@ -4,95 +4,20 @@
 
 use aoc::parser::*;
 
-pub const EXAMPLE1: &str = "x00: 1
-x01: 1
-x02: 1
-y00: 0
-y01: 1
-y02: 0
-
-x00 AND y00 -> z00
-x01 XOR y01 -> z01
-x02 OR y02 -> z02
-";
-
-pub const EXAMPLE2: &str = "x00: 1
-x01: 0
-x02: 1
-x03: 1
-x04: 0
-y00: 1
-y01: 1
-y02: 1
-y03: 1
-y04: 1
-
-ntg XOR fgs -> mjb
-y02 OR x01 -> tnw
-kwq OR kpj -> z05
-x00 OR x03 -> fst
-tgd XOR rvg -> z01
-vdt OR tnw -> bfw
-bfw AND frj -> z10
-ffh OR nrd -> bqk
-y00 AND y03 -> djm
-y03 OR y00 -> psh
-bqk OR frj -> z08
-tnw OR fst -> frj
-gnj AND tgd -> z11
-bfw XOR mjb -> z00
-x03 OR x00 -> vdt
-gnj AND wpb -> z02
-x04 AND y00 -> kjc
-djm OR pbm -> qhw
-nrd AND vdt -> hwm
-kjc AND fst -> rvg
-y04 OR y02 -> fgs
-y01 AND x02 -> pbm
-ntg OR kjc -> kwq
-psh XOR fgs -> tgd
-qhw XOR tgd -> z09
-pbm OR djm -> kpj
-x03 XOR y03 -> ffh
-x00 XOR y04 -> ntg
-bfw OR bqk -> z06
-nrd XOR fgs -> wpb
-frj XOR qhw -> z04
-bqk OR frj -> z07
-y03 OR x01 -> nrd
-hwm AND bqk -> z03
-tgd XOR rvg -> z12
-tnw OR pbm -> gnj
-";
-
-pub const EXAMPLE3: &str = "x00: 0
-x01: 1
-x02: 0
-x03: 1
-x04: 0
-x05: 1
-y00: 0
-y01: 0
-y02: 1
-y03: 1
-y04: 0
-y05: 1
-
-x00 AND y00 -> z05
-x01 AND y01 -> z02
-x02 AND y02 -> z01
-x03 AND y03 -> z03
-x04 AND y04 -> z04
-x05 AND y05 -> z00
-";
+pub const EXAMPLE1: &str = include_str!("../examples/example1.txt");
+
+pub const EXAMPLE2: &str = include_str!("../examples/example2.txt");
+
+pub const EXAMPLE3: &str = include_str!("../examples/example3.txt");
 
 use super::*;
 
 type ParseResult = (Vec<(Wire, bool)>, Vec<Connection>);
 
 fn wire(input: &str) -> IResult<&str, Wire> {
-    let (input, name) = character::alphanumeric1(input)?;
-    Ok((input, Wire(name.try_into().unwrap())))
+    combinator::map_res(character::alphanumeric1, |name: &str| {
+        name.try_into().map(Wire)
+    })(input)
 }
 
 fn wire_init(input: &str) -> IResult<&str, (Wire, bool)> {
@@ -140,10 +65,36 @@ fn all(input: &str) -> IResult<&str, ParseResult> {
     Ok((input, (inits, conns)))
 }
 
-pub fn parse(mut bufin: impl BufRead) -> Result<ParseResult> {
+pub fn parse0(mut bufin: impl BufRead) -> Result<ParseResult> {
     aoc::parse_with!(all, bufin)
 }
 
+pub fn parse(bufin: impl BufRead) -> Result<ParseResult> {
+    let (inits, conns) = parse0(bufin)?;
+    validate(&inits, &conns).into_result()?;
+    Ok((inits, conns))
+}
+
+pub fn unparse((inits, conns): &ParseResult) -> String {
+    let inits = inits
+        .iter()
+        .map(|(wire, value)| format!("{}: {}\n", wire, u8::from(*value)))
+        .collect::<String>();
+    let conns = conns
+        .iter()
+        .map(|(output, gate)| {
+            let op = match gate.op {
+                Operation::And => "AND",
+                Operation::Or => "OR",
+                Operation::Xor => "XOR",
+            };
+            let [wire1, wire2] = gate.inputs;
+            format!("{} {} {} -> {}\n", wire1, op, wire2, output)
+        })
+        .collect::<String>();
+    format!("{}\n{}", inits, conns)
+}
+
 #[test]
 fn test1() -> Result<()> {
     let input = parser::parse(EXAMPLE1.as_bytes())?;
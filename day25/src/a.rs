@@ -0,0 +1,24 @@
+// Copyright (C) 2024 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use crate::*;
+
+use rayon::prelude::*;
+
+fn fits(lock: &Lock, key: &Key) -> bool {
+    lock.0
+        .par_iter()
+        .enumerate()
+        .all(|(i, pin)| pin + key.0[i] <= 6)
+}
+
+pub fn process(bufin: impl BufRead) -> Result<usize> {
+    let (locks, keys) = parser::parse(bufin)?;
+    Ok(locks
+        .into_par_iter()
+        .map(|lock| keys.iter().filter(|key| fits(&lock, key)).count())
+        .sum())
+}
+
+example_tests!("a", process);
@@ -4,105 +4,119 @@
 
 pub use aoc::*;
 
-pub const EXAMPLE: &str = "#####
-.####
-.####
-.####
-.#.#.
-.#...
-.....
-
-#####
-##.##
-.#.##
-...##
-...#.
-...#.
-.....
-
-.....
-#....
-#....
-#...#
-#.#.#
-#.###
-#####
-
-.....
-.....
-#.#..
-###..
-###.#
-###.#
-#####
-
-.....
-.....
-.....
-#....
-#.#..
-#.#.#
-#####
-";
+pub mod a;
+
+solutions! {
+    day: 25,
+    'a' => |bufin| a::process(bufin),
+}
+
+dumper! {
+    day: 25,
+    parser::parse0,
+    parser::unparse,
+}
+
+pub const EXAMPLE: &str = include_str!("../examples/example.txt");
 
 pub struct Lock(pub [u8; 5]);
 pub struct Key(pub [u8; 5]);
 
+validator! {
+    day: 25,
+    |bufin| Ok(validate(&parser::parse0(bufin)?)),
+}
+
+/// Every block must have 5 columns and 7 lines, with the first line
+/// full for locks and empty for keys, and the last one the opposite
+pub fn validate(blocks: &[Vec<Vec<char>>]) -> validate::Violations {
+    let mut violations = validate::Violations::default();
+    let mut line = 1;
+    for block in blocks {
+        let location = Location { line, column: 1 };
+        line += block.len() + 1;
+        if block.len() != 7 {
+            violations.at(
+                location,
+                format!("block has {} lines, expected 7", block.len()),
+            );
+            continue;
+        }
+        let mut wide = true;
+        for (y, row) in block.iter().enumerate() {
+            if row.len() != 5 {
+                violations.at(
+                    Location {
+                        line: location.line + y,
+                        column: 1,
+                    },
+                    format!("line has {} cells, expected 5", row.len()),
+                );
+                wide = false;
+            }
+        }
+        if !wide {
+            continue;
+        }
+        let full = |row: &[char], c: char| row.iter().all(|r| *r == c);
+        let bottom = if full(&block[0], '#') {
+            '.'
+        } else if full(&block[0], '.') {
+            '#'
+        } else {
+            violations.at(location, "first line is neither ##### nor .....");
+            continue;
+        };
+        if !full(&block[6], bottom) {
+            violations.at(
+                Location {
+                    line: location.line + 6,
+                    column: 1,
+                },
+                format!("last line should be {}", bottom.to_string().repeat(5)),
+            );
+        }
+    }
+    violations
+}
+
 pub mod parser {
     use aoc::parser::*;
 
     use super::*;
 
-    enum LockKey {
-        Lock(Lock),
-        Key(Key),
-    }
-
     fn cell(input: &str) -> IResult<&str, char> {
         let (input, g) = character::one_of(".#")(input)?;
         Ok((input, g))
     }
 
-    fn lockkey(input: &str) -> IResult<&str, [u8; 5]> {
-        let (input, _) = character::newline(input)?;
-        let (input, g) = grid(cell)(input)?;
-        let contents = (0_usize..5)
-            .map(|x| (0_usize..6).filter(|y| g[*y][x] == '#').count() as u8)
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
-        Ok((input, contents))
-    }
-
-    fn key(input: &str) -> IResult<&str, LockKey> {
-        let (input, _) = tag(".....")(input)?;
-        let (input, c) = lockkey(input)?;
-        Ok((input, LockKey::Key(Key(c))))
-    }
-
-    fn lock(input: &str) -> IResult<&str, LockKey> {
-        let (input, _) = tag("#####")(input)?;
-        let (input, c) = lockkey(input)?;
-        Ok((input, LockKey::Lock(Lock(c))))
+    pub fn parse0(mut bufin: impl BufRead) -> Result<Vec<Vec<Vec<char>>>> {
+        aoc::parse_with!(multi::separated_list1(newline, grid(cell)), bufin)
     }
 
-    fn all(input: &str) -> IResult<&str, (Vec<Lock>, Vec<Key>)> {
-        let (input, lockkeys) = multi::separated_list1(tag("\n"), branch::alt((lock, key)))(input)?;
-        let (locks, keys) =
-            lockkeys
-                .into_iter()
-                .fold((vec![], vec![]), |(mut locks, mut keys), lockkey| {
-                    match lockkey {
-                        LockKey::Lock(lock) => locks.push(lock),
-                        LockKey::Key(key) => keys.push(key),
-                    };
-                    (locks, keys)
-                });
-        Ok((input, (locks, keys)))
+    pub fn parse(bufin: impl BufRead) -> Result<(Vec<Lock>, Vec<Key>)> {
+        let blocks = parse0(bufin)?;
+        validate(&blocks).into_result()?;
+        let mut locks = vec![];
+        let mut keys = vec![];
+        for block in blocks {
+            let contents =
+                [0, 1, 2, 3, 4].map(|x| (1_usize..7).filter(|y| block[*y][x] == '#').count() as u8);
+            if block[0][0] == '#' {
+                locks.push(Lock(contents));
+            } else {
+                keys.push(Key(contents));
+            }
+        }
+        Ok((locks, keys))
     }
 
-    pub fn parse(mut bufin: impl BufRead) -> Result<(Vec<Lock>, Vec<Key>)> {
-        aoc::parse_with!(all, bufin)
+    pub fn unparse(blocks: &[Vec<Vec<char>>]) -> String {
+        blocks
+            .iter()
+            .map(|block| aoc::dump::grid_text(block, |c| *c))
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 }
 
@@ -113,3 +127,22 @@ fn test() -> Result<()> {
     assert_eq!(keys.len(), 3);
     Ok(())
 }
+
+#[test]
+fn test_validate() -> Result<()> {
+    let input = "#####\n.....\n\n#####\n.#...\n.#...\n.....\n.....\n.##..\n#####\n";
+    let violations = validate(&parser::parse0(input.as_bytes())?)
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        violations,
+        vec![
+            "line 1, column 1: block has 2 lines, expected 7",
+            "line 10, column 1: last line should be .....",
+        ]
+    );
+    assert!(parser::parse(input.as_bytes()).is_err());
+    assert!(validate(&parser::parse0(EXAMPLE.as_bytes())?).is_empty());
+    Ok(())
+}